@@ -0,0 +1,370 @@
+//! `QuickBooks` batch operations
+//!
+//! Types for the `/batch` endpoint, which accepts up to 30 create, update, delete or query
+//! operations in a single request. Each operation carries a caller-chosen `bId` that is
+//! echoed back on its response.
+//!
+//! API reference: <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/batch>
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    query::{Query, QueryResponse},
+    request, Fault, QBCreatable, QBDeletable, QBEntity, QBFullUpdatable, QBItem, QBQueryable,
+    QBTypeError,
+};
+
+/// Maximum number of operations accepted in a single batch request.
+pub const MAX_BATCH_ITEMS: usize = 30;
+
+/// Operation performed on the entity of a [`BatchItemRequest`].
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BatchOperation {
+    Create,
+    Update,
+    Delete,
+}
+
+impl BatchOperation {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BatchOperation::Create => "create",
+            BatchOperation::Update => "update",
+            BatchOperation::Delete => "delete",
+        }
+    }
+}
+
+/// A single operation within a batch request.
+///
+/// Entity operations serialize as `{"bId": "1", "operation": "create", "Invoice": {...}}`,
+/// query operations as `{"bId": "2", "Query": "SELECT * FROM Invoice"}`.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BatchItemRequest {
+    /// Caller-chosen ID used to match the response
+    #[serde(rename = "bId")]
+    pub b_id: String,
+    /// Operation to perform on `entity`
+    pub operation: Option<BatchOperation>,
+    /// Entity payload for create / update / delete
+    #[serde(flatten)]
+    pub entity: Option<QBEntity>,
+    /// Query statement for query operations
+    #[serde(rename = "Query")]
+    pub query: Option<String>,
+}
+
+/// Body of a `/batch` request.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct BatchRequest {
+    #[serde(rename = "BatchItemRequest")]
+    pub items: Vec<BatchItemRequest>,
+}
+
+/// Builds a [`BatchRequest`], enforcing the item limit, unique `bId`s, and the local
+/// preconditions of each operation (`validate_create`, `validate_update`, `can_delete`).
+///
+/// ```
+/// use quickbooks_types::{batch::BatchBuilder, Customer, QBQueryable};
+///
+/// let customer = Customer {
+///     display_name: Some("John Doe".into()),
+///     ..Default::default()
+/// };
+///
+/// let mut batch = BatchBuilder::new();
+/// batch.create("1", customer).unwrap();
+/// batch.query("2", Customer::query().max_results(10)).unwrap();
+///
+/// // Missing a display name, so it cannot be created
+/// assert!(batch.create("3", Customer::default()).is_err());
+/// assert_eq!(batch.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BatchBuilder {
+    items: Vec<BatchItemRequest>,
+    ids: HashSet<String>,
+}
+
+impl BatchBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a create operation; `entity` must pass [`QBCreatable::validate_create()`].
+    pub fn create<T>(
+        &mut self,
+        b_id: impl Into<String>,
+        entity: T,
+    ) -> Result<&mut Self, QBTypeError>
+    where
+        T: QBItem + QBCreatable + Into<QBEntity>,
+    {
+        request::validated::<T>(entity.validate_create(), BatchOperation::Create.as_str())?;
+        self.push_entity(b_id.into(), BatchOperation::Create, entity.into())
+    }
+
+    /// Adds a full update operation; `entity` must pass [`QBFullUpdatable::validate_update()`].
+    pub fn update<T>(
+        &mut self,
+        b_id: impl Into<String>,
+        entity: T,
+    ) -> Result<&mut Self, QBTypeError>
+    where
+        T: QBItem + QBFullUpdatable + Into<QBEntity>,
+    {
+        request::validated::<T>(entity.validate_update(), BatchOperation::Update.as_str())?;
+        self.push_entity(b_id.into(), BatchOperation::Update, entity.into())
+    }
+
+    /// Adds a delete operation; `entity` must satisfy [`QBDeletable::can_delete()`].
+    pub fn delete<T>(
+        &mut self,
+        b_id: impl Into<String>,
+        entity: T,
+    ) -> Result<&mut Self, QBTypeError>
+    where
+        T: QBDeletable + Into<QBEntity>,
+    {
+        if !entity.can_delete() {
+            return Err(QBTypeError::PreconditionFailed {
+                entity: T::name(),
+                operation: BatchOperation::Delete.as_str(),
+            });
+        }
+        self.push_entity(b_id.into(), BatchOperation::Delete, entity.into())
+    }
+
+    /// Adds a query operation.
+    pub fn query<T: QBQueryable>(
+        &mut self,
+        b_id: impl Into<String>,
+        query: Query<T>,
+    ) -> Result<&mut Self, QBTypeError> {
+        self.push(BatchItemRequest {
+            b_id: b_id.into(),
+            operation: None,
+            entity: None,
+            query: Some(query.to_query_string()),
+        })
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    #[must_use]
+    pub fn build(self) -> BatchRequest {
+        BatchRequest { items: self.items }
+    }
+
+    fn push_entity(
+        &mut self,
+        b_id: String,
+        operation: BatchOperation,
+        entity: QBEntity,
+    ) -> Result<&mut Self, QBTypeError> {
+        self.push(BatchItemRequest {
+            b_id,
+            operation: Some(operation),
+            entity: Some(entity),
+            query: None,
+        })
+    }
+
+    fn push(&mut self, item: BatchItemRequest) -> Result<&mut Self, QBTypeError> {
+        if self.items.len() >= MAX_BATCH_ITEMS {
+            return Err(QBTypeError::BatchLimitExceeded(MAX_BATCH_ITEMS));
+        }
+        if !self.ids.insert(item.b_id.clone()) {
+            return Err(QBTypeError::DuplicateBatchId(item.b_id));
+        }
+        self.items.push(item);
+        Ok(self)
+    }
+}
+
+/// Result of a single operation within a batch response.
+///
+/// Exactly one of `entity`, `fault` or `query_response` is expected to be present.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BatchItemResponse {
+    /// `bId` of the originating request item
+    #[serde(rename = "bId")]
+    pub b_id: String,
+    /// Entity returned by a create / update / delete operation
+    #[serde(flatten)]
+    pub entity: Option<QBEntity>,
+    /// Error returned in place of the entity
+    #[serde(rename = "Fault")]
+    pub fault: Option<Fault>,
+    /// Raw result of a query operation, see [`BatchItemResponse::query_response()`]
+    #[serde(rename = "QueryResponse")]
+    pub query_response: Option<serde_json::Value>,
+}
+
+impl BatchItemResponse {
+    /// Returns the typed entity, or the fault returned in its place.
+    pub fn into_entity<T>(self) -> Result<T, QBTypeError>
+    where
+        T: QBItem + TryFrom<QBEntity, Error = QBEntity>,
+    {
+        if let Some(fault) = self.fault {
+            return Err(fault.into());
+        }
+        self.entity
+            .and_then(|e| T::try_from(e).ok())
+            .ok_or(QBTypeError::UnexpectedBatchPayload {
+                b_id: self.b_id,
+                expected: T::name(),
+            })
+    }
+
+    /// Returns the typed query result, or the fault returned in its place.
+    pub fn query_response<T: QBItem>(&self) -> Result<QueryResponse<T>, QBTypeError> {
+        if let Some(fault) = &self.fault {
+            return Err(fault.clone().into());
+        }
+        self.query_response
+            .as_ref()
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .ok_or_else(|| QBTypeError::UnexpectedBatchPayload {
+                b_id: self.b_id.clone(),
+                expected: "QueryResponse",
+            })
+    }
+}
+
+/// Body of a `/batch` response.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct BatchResponse {
+    #[serde(rename = "BatchItemResponse", default)]
+    pub items: Vec<BatchItemResponse>,
+    pub time: Option<DateTime<FixedOffset>>,
+}
+
+impl BatchResponse {
+    /// Response item for the request with the given `bId`.
+    #[must_use]
+    pub fn get(&self, b_id: &str) -> Option<&BatchItemResponse> {
+        self.items.iter().find(|item| item.b_id == b_id)
+    }
+
+    /// Removes and returns the typed entity for `b_id`, or the fault returned in its place.
+    pub fn take_entity<T>(&mut self, b_id: &str) -> Result<T, QBTypeError>
+    where
+        T: QBItem + TryFrom<QBEntity, Error = QBEntity>,
+    {
+        let index = self
+            .items
+            .iter()
+            .position(|item| item.b_id == b_id)
+            .ok_or_else(|| QBTypeError::UnexpectedBatchPayload {
+                b_id: b_id.into(),
+                expected: T::name(),
+            })?;
+        self.items.remove(index).into_entity()
+    }
+
+    /// Iterates over the items that failed, with their faults.
+    pub fn faults(&self) -> impl Iterator<Item = (&str, &Fault)> {
+        self.items
+            .iter()
+            .filter_map(|item| item.fault.as_ref().map(|f| (item.b_id.as_str(), f)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Customer, Invoice, QBFaultKind};
+
+    #[test]
+    fn builder_enforces_limit_and_ids() {
+        let customer = Customer {
+            display_name: Some("John Doe".into()),
+            ..Default::default()
+        };
+        let mut batch = BatchBuilder::new();
+        for i in 0..MAX_BATCH_ITEMS {
+            batch.create(i.to_string(), customer.clone()).unwrap();
+        }
+        assert!(matches!(
+            batch.create("extra", customer.clone()),
+            Err(QBTypeError::BatchLimitExceeded(MAX_BATCH_ITEMS))
+        ));
+
+        let mut batch = BatchBuilder::new();
+        batch.create("1", customer.clone()).unwrap();
+        assert!(matches!(
+            batch.create("1", customer.clone()),
+            Err(QBTypeError::DuplicateBatchId(_))
+        ));
+        // Not read from QuickBooks yet
+        assert!(batch.update("2", customer.clone()).is_err());
+        assert!(batch.delete("3", Invoice::default()).is_err());
+
+        let json = serde_json::to_value(batch.build()).unwrap();
+        assert_eq!(json["BatchItemRequest"][0]["bId"], "1");
+        assert_eq!(json["BatchItemRequest"][0]["operation"], "create");
+        assert_eq!(
+            json["BatchItemRequest"][0]["Customer"]["DisplayName"],
+            "John Doe"
+        );
+    }
+
+    #[test]
+    fn response_maps_back_by_bid() {
+        let json = r#"{
+          "BatchItemResponse": [
+            { "bId": "1", "Customer": { "Id": "58", "SyncToken": "0", "DisplayName": "John Doe" } },
+            {
+              "bId": "2",
+              "Fault": {
+                "type": "ValidationFault",
+                "Error": [{ "Message": "Duplicate Name Exists Error", "code": "6240" }]
+              }
+            },
+            {
+              "bId": "3",
+              "QueryResponse": { "Invoice": [{ "Id": "130" }], "startPosition": 1, "maxResults": 1 }
+            }
+          ],
+          "time": "2024-05-01T10:00:00.000-07:00"
+        }"#;
+        let mut response: BatchResponse = serde_json::from_str(json).unwrap();
+
+        let page = response
+            .get("3")
+            .unwrap()
+            .query_response::<Invoice>()
+            .unwrap();
+        assert_eq!(page.items[0].id.as_deref(), Some("130"));
+
+        let faults: Vec<_> = response.faults().map(|(id, _)| id).collect();
+        assert_eq!(faults, ["2"]);
+        let err = response.take_entity::<Customer>("2").unwrap_err();
+        assert_eq!(err.fault_kind(), Some(QBFaultKind::DuplicateName));
+
+        assert!(response.take_entity::<Invoice>("1").is_err());
+        let mut response: BatchResponse = serde_json::from_str(json).unwrap();
+        let customer: Customer = response.take_entity("1").unwrap();
+        assert_eq!(customer.id.as_deref(), Some("58"));
+    }
+}
@@ -0,0 +1,343 @@
+//! `QuickBooks` change data capture
+//!
+//! Types for the `/cdc` endpoint, which returns every entity of the requested types that changed
+//! since a point in time, including `status: "Deleted"` tombstones, and [`apply_cdc`] to merge
+//! those changes into a local mirror.
+//!
+//! API reference: <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/changedatacapture>
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use serde_with::skip_serializing_none;
+
+use crate::{Fault, QBItem, QBTypeError};
+
+/// Top-level body of a `/cdc` response.
+///
+/// ```
+/// use quickbooks_types::{cdc::{CdcChange, CdcResponse}, Customer};
+///
+/// let json = r#"{
+///   "CDCResponse": [{
+///     "QueryResponse": [
+///       { "Customer": [{ "Id": "58", "SyncToken": "1", "DisplayName": "John Doe" }] },
+///       { "Customer": [{ "domain": "QBO", "status": "Deleted", "Id": "59",
+///                        "MetaData": { "LastUpdatedTime": "2024-05-01T10:00:00-07:00" } }] }
+///     ]
+///   }],
+///   "time": "2024-05-01T10:05:00.000-07:00"
+/// }"#;
+///
+/// let response: CdcResponse = serde_json::from_str(json).unwrap();
+/// let changes = response.changes::<Customer>().unwrap();
+/// assert!(matches!(changes[0], CdcChange::Updated(_)));
+/// assert!(matches!(changes[1], CdcChange::Deleted(_)));
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CdcResponse {
+    /// One entry per CDC request, each holding the per-entity change lists
+    #[serde(rename = "CDCResponse", default)]
+    pub responses: Vec<CdcQueryResponses>,
+    /// Error payload, present when the request failed
+    #[serde(rename = "Fault", alias = "fault")]
+    pub fault: Option<Fault>,
+    /// Server time the response was produced; use it as the next `changedSince`
+    pub time: Option<DateTime<FixedOffset>>,
+}
+
+/// Per-entity change lists within a [`CdcResponse`].
+///
+/// Each object is keyed by entity name (e.g. `{"Customer": [...]}`) alongside paging fields, so
+/// the lists are kept raw and decoded by [`CdcResponse::changes()`].
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CdcQueryResponses {
+    #[serde(rename = "QueryResponse", default)]
+    pub query_responses: Vec<Map<String, Value>>,
+}
+
+impl CdcResponse {
+    /// All changes for entity type `T`, in the order the server returned them.
+    pub fn changes<T: QBItem>(&self) -> Result<Vec<CdcChange<T>>, QBTypeError> {
+        if let Some(fault) = &self.fault {
+            return Err(fault.clone().into());
+        }
+        let mut out = Vec::new();
+        for list in self
+            .responses
+            .iter()
+            .flat_map(|r| &r.query_responses)
+            .filter_map(|r| r.get(T::name()))
+        {
+            out.extend(Vec::<CdcChange<T>>::deserialize(list).map_err(|e| {
+                QBTypeError::InvalidPayload {
+                    entity: T::name(),
+                    message: e.to_string(),
+                }
+            })?);
+        }
+        Ok(out)
+    }
+}
+
+/// A single entry of a CDC change list: either the current version of an entity or a
+/// tombstone for one that was deleted.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CdcChange<T> {
+    Updated(T),
+    Deleted(CdcTombstone),
+}
+
+impl<T: QBItem> CdcChange<T> {
+    #[must_use]
+    pub fn id(&self) -> Option<&String> {
+        match self {
+            CdcChange::Updated(e) => e.id(),
+            CdcChange::Deleted(t) => Some(&t.id),
+        }
+    }
+
+    #[must_use]
+    pub fn last_updated_time(&self) -> Option<DateTime<Utc>> {
+        match self {
+            CdcChange::Updated(e) => e.meta_data().map(|m| m.last_updated_time),
+            CdcChange::Deleted(t) => t.last_updated_time(),
+        }
+    }
+}
+
+impl<'de, T: QBItem> Deserialize<'de> for CdcChange<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        // Tombstones only carry `Id` and `MetaData.LastUpdatedTime`, which would not
+        // deserialize as the full entity.
+        if value.get("status").and_then(Value::as_str) == Some("Deleted") {
+            CdcTombstone::deserialize(value)
+                .map(CdcChange::Deleted)
+                .map_err(serde::de::Error::custom)
+        } else {
+            T::deserialize(value)
+                .map(CdcChange::Updated)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+/// `CdcTombstone`
+///
+/// Marker for an entity deleted since the CDC `changedSince` time.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CdcTombstone {
+    /// ID of the deleted entity
+    #[serde(rename = "Id")]
+    pub id: String,
+    /// Always `"Deleted"`
+    pub status: Option<String>,
+    /// Data domain, usually `"QBO"`
+    pub domain: Option<String>,
+    /// Deletion time
+    #[serde(rename = "MetaData")]
+    pub meta_data: Option<CdcTombstoneMetaData>,
+}
+
+impl CdcTombstone {
+    #[must_use]
+    pub fn last_updated_time(&self) -> Option<DateTime<Utc>> {
+        self.meta_data.as_ref().and_then(|m| m.last_updated_time)
+    }
+}
+
+/// `MetaData` of a [`CdcTombstone`], which omits `CreateTime`.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CdcTombstoneMetaData {
+    pub last_updated_time: Option<DateTime<Utc>>,
+}
+
+/// Counts of what [`apply_cdc`] did with each change.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CdcMergeStats {
+    /// Entities added to the map
+    pub inserted: usize,
+    /// Entities replaced with a newer version
+    pub updated: usize,
+    /// Entities removed by a tombstone
+    pub deleted: usize,
+    /// Changes ignored because the local copy is as new or newer, or the change has no ID
+    pub skipped: usize,
+}
+
+/// Merges CDC `changes` into `local`, keyed by [`QBItem::id()`].
+///
+/// A change only replaces or removes the local copy when it is newer: first by
+/// `MetaData.LastUpdatedTime`, then by `SyncToken` when the times are equal or missing.
+/// Tombstones carry no sync token, so an equal time removes the entity.
+pub fn apply_cdc<T: QBItem>(
+    local: &mut HashMap<String, T>,
+    changes: impl IntoIterator<Item = CdcChange<T>>,
+) -> CdcMergeStats {
+    let mut stats = CdcMergeStats::default();
+    for change in changes {
+        let Some(id) = change.id().cloned() else {
+            stats.skipped += 1;
+            continue;
+        };
+        let existing = local.get(&id);
+        match change {
+            CdcChange::Updated(entity) => match existing {
+                None => {
+                    local.insert(id, entity);
+                    stats.inserted += 1;
+                }
+                Some(current) if is_newer(&entity, current) => {
+                    local.insert(id, entity);
+                    stats.updated += 1;
+                }
+                Some(_) => stats.skipped += 1,
+            },
+            CdcChange::Deleted(tombstone) => {
+                let stale = match (existing, tombstone.last_updated_time()) {
+                    (None, _) => true,
+                    (Some(current), Some(deleted_at)) => current
+                        .meta_data()
+                        .is_some_and(|m| m.last_updated_time > deleted_at),
+                    (Some(_), None) => false,
+                };
+                if stale {
+                    stats.skipped += 1;
+                } else {
+                    local.remove(&id);
+                    stats.deleted += 1;
+                }
+            }
+        }
+    }
+    stats
+}
+
+fn is_newer<T: QBItem>(incoming: &T, current: &T) -> bool {
+    let time = |e: &T| e.meta_data().map(|m| m.last_updated_time);
+    let by_time = match (time(incoming), time(current)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        _ => Ordering::Equal,
+    };
+    let token = |e: &T| e.sync_token().and_then(|t| t.parse::<u64>().ok());
+    match by_time {
+        Ordering::Equal => match (token(incoming), token(current)) {
+            (Some(a), Some(b)) => a > b,
+            // Nothing to compare against, trust the server
+            _ => true,
+        },
+        ordering => ordering == Ordering::Greater,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{common::MetaData, Customer};
+
+    fn customer(id: &str, token: &str, updated: &str, name: &str) -> Customer {
+        Customer {
+            id: Some(id.into()),
+            sync_token: Some(token.into()),
+            meta_data: Some(MetaData {
+                create_time: "2024-01-01T00:00:00Z".parse().unwrap(),
+                last_updated_time: updated.parse().unwrap(),
+            }),
+            display_name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    fn tombstone(id: &str, updated: &str) -> CdcChange<Customer> {
+        CdcChange::Deleted(CdcTombstone {
+            id: id.into(),
+            status: Some("Deleted".into()),
+            domain: None,
+            meta_data: Some(CdcTombstoneMetaData {
+                last_updated_time: Some(updated.parse().unwrap()),
+            }),
+        })
+    }
+
+    #[test]
+    fn merge_discards_older_versions() {
+        let mut local = HashMap::new();
+        for c in [
+            customer("1", "2", "2024-05-01T10:00:00Z", "Current"),
+            customer("2", "0", "2024-05-01T10:00:00Z", "Kept"),
+            customer("3", "0", "2024-05-01T10:00:00Z", "Removed"),
+        ] {
+            local.insert(c.id.clone().unwrap(), c);
+        }
+
+        let stats = apply_cdc(
+            &mut local,
+            [
+                // Older than the local copy
+                CdcChange::Updated(customer("1", "1", "2024-04-01T10:00:00Z", "Old")),
+                // Same time, higher sync token
+                CdcChange::Updated(customer("2", "1", "2024-05-01T10:00:00Z", "Renamed")),
+                // Same time, same token
+                CdcChange::Updated(customer("2", "1", "2024-05-01T10:00:00Z", "Dup")),
+                CdcChange::Updated(customer("4", "0", "2024-05-02T10:00:00Z", "New")),
+                tombstone("3", "2024-05-02T10:00:00Z"),
+                // Deleted before the local copy was last updated
+                tombstone("1", "2024-04-30T10:00:00Z"),
+                tombstone("9", "2024-05-02T10:00:00Z"),
+            ],
+        );
+
+        assert_eq!(
+            stats,
+            CdcMergeStats {
+                inserted: 1,
+                updated: 1,
+                deleted: 1,
+                skipped: 4,
+            }
+        );
+        assert_eq!(local["1"].display_name.as_deref(), Some("Current"));
+        assert_eq!(local["2"].display_name.as_deref(), Some("Renamed"));
+        assert_eq!(local["4"].display_name.as_deref(), Some("New"));
+        assert!(!local.contains_key("3"));
+    }
+
+    #[test]
+    fn decode_changes_per_entity() {
+        let json = r#"{
+          "CDCResponse": [{
+            "QueryResponse": [
+              {
+                "Customer": [
+                  { "Id": "58", "SyncToken": "1", "DisplayName": "John Doe",
+                    "MetaData": { "CreateTime": "2024-01-01T00:00:00-07:00",
+                                  "LastUpdatedTime": "2024-05-01T10:00:00-07:00" } },
+                  { "domain": "QBO", "status": "Deleted", "Id": "59",
+                    "MetaData": { "LastUpdatedTime": "2024-05-01T10:00:00-07:00" } }
+                ],
+                "startPosition": 1, "maxResults": 2, "totalCount": 2
+              },
+              { "Invoice": [{ "Id": "130", "SyncToken": "0" }] }
+            ]
+          }],
+          "time": "2024-05-01T10:05:00.000-07:00"
+        }"#;
+        let response: CdcResponse = serde_json::from_str(json).unwrap();
+        let changes = response.changes::<Customer>().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[1].id().map(String::as_str), Some("59"));
+        assert!(changes[1].last_updated_time().is_some());
+        assert_eq!(response.changes::<crate::Invoice>().unwrap().len(), 1);
+        assert!(response.changes::<crate::Item>().unwrap().is_empty());
+    }
+}
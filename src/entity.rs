@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::MetaData, Account, Attachable, Bill, BillPayment, Budget, Class, CompanyCurrency,
+    CompanyInfo, CreditMemo, Customer, Department, Deposit, Employee, Estimate, Exchangerate,
+    InventoryAdjustment, Invoice, Item, JournalEntry, Payment, PaymentMethod, Preferences,
+    Purchase, PurchaseOrder, QBItem, RecurringTransaction, RefundReceipt, SalesReceipt, TaxAgency,
+    TaxCode, TaxRate, Term, TimeActivity, Transfer, Vendor, VendorCredit,
+};
+
+macro_rules! impl_qb_entity {
+    ($($x:ident),+ $(,)?) => {
+        /// `QBEntity`
+        ///
+        /// Any top-level `QuickBooks` entity, tagged by its [`QBItem::name()`].
+        /// Serializes as `{"<Entity>": {...}}`, the shape used by batch payloads.
+        #[allow(clippy::large_enum_variant)]
+        #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+        pub enum QBEntity {
+            $($x($x),)+
+        }
+
+        impl QBEntity {
+            /// Entity type name, e.g. `"Invoice"`.
+            #[must_use]
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(QBEntity::$x(_) => $x::name(),)+
+                }
+            }
+
+            #[must_use]
+            pub fn id(&self) -> Option<&String> {
+                match self {
+                    $(QBEntity::$x(e) => e.id(),)+
+                }
+            }
+
+            #[must_use]
+            pub fn sync_token(&self) -> Option<&String> {
+                match self {
+                    $(QBEntity::$x(e) => e.sync_token(),)+
+                }
+            }
+
+            #[must_use]
+            pub fn meta_data(&self) -> Option<&MetaData> {
+                match self {
+                    $(QBEntity::$x(e) => e.meta_data(),)+
+                }
+            }
+        }
+
+        /// `QBEntityKind`
+        ///
+        /// The type of a top-level `QuickBooks` entity, without its data.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+        pub enum QBEntityKind {
+            $($x,)+
+        }
+
+        impl QBEntityKind {
+            /// Every entity kind supported by this crate.
+            pub const ALL: &'static [QBEntityKind] = &[$(QBEntityKind::$x,)+];
+
+            /// Entity type name, e.g. `"Invoice"`.
+            #[must_use]
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(QBEntityKind::$x => $x::name(),)+
+                }
+            }
+
+            /// Looks up an entity kind by name, ignoring ASCII case.
+            #[must_use]
+            pub fn from_name(name: &str) -> Option<Self> {
+                Self::ALL
+                    .iter()
+                    .copied()
+                    .find(|kind| kind.name().eq_ignore_ascii_case(name))
+            }
+        }
+
+        impl std::fmt::Display for QBEntityKind {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+
+        impl QBEntity {
+            #[must_use]
+            pub fn kind(&self) -> QBEntityKind {
+                match self {
+                    $(QBEntity::$x(_) => QBEntityKind::$x,)+
+                }
+            }
+        }
+
+        $(
+            impl From<$x> for QBEntity {
+                fn from(value: $x) -> Self {
+                    QBEntity::$x(value)
+                }
+            }
+
+            impl TryFrom<QBEntity> for $x {
+                type Error = QBEntity;
+
+                fn try_from(value: QBEntity) -> Result<Self, Self::Error> {
+                    match value {
+                        QBEntity::$x(e) => Ok(e),
+                        other => Err(other),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+crate::for_each_qb_item!(@all impl_qb_entity);
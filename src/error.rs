@@ -1,3 +1,8 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::ValidationIssue;
+
 /// Errors for Quickbooks validation / processing
 #[derive(Debug, thiserror::Error)]
 pub enum QBTypeError {
@@ -8,4 +13,283 @@ pub enum QBTypeError {
     MissingField(&'static str),
     #[error("QB Item could not be referenced!")]
     QBToRefError,
+    #[error("QuickBooks returned a fault: {0}")]
+    Fault(#[from] Fault),
+    #[error("{entity} does not meet the preconditions for {operation}")]
+    PreconditionFailed {
+        entity: &'static str,
+        operation: &'static str,
+    },
+    #[error("{entity} is not valid for {operation}: {}", join_issues(.issues))]
+    ValidationFailed {
+        entity: &'static str,
+        operation: &'static str,
+        issues: Vec<ValidationIssue>,
+    },
+    #[error("Batch requests are limited to {0} items")]
+    BatchLimitExceeded(usize),
+    #[error("Duplicate batch item ID: {0}")]
+    DuplicateBatchId(String),
+    #[error("Batch item {b_id} did not return a {expected}")]
+    UnexpectedBatchPayload {
+        b_id: String,
+        expected: &'static str,
+    },
+    #[error("Cannot apply {credit} to {target}: {reason}")]
+    CannotApply {
+        credit: &'static str,
+        target: &'static str,
+        reason: &'static str,
+    },
+    #[error("Cannot compare {entity} with report: {reason}")]
+    ReportMismatch {
+        entity: &'static str,
+        reason: &'static str,
+    },
+    #[error("Exchange rate {found} does not convert {expected}")]
+    CurrencyMismatch { expected: String, found: String },
+    #[error("Could not decode {entity}: {message}")]
+    InvalidPayload {
+        entity: &'static str,
+        message: String,
+    },
+}
+
+fn join_issues(issues: &[ValidationIssue]) -> String {
+    issues
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl QBTypeError {
+    /// Classification of the server fault, if this error came from one.
+    #[must_use]
+    pub fn fault_kind(&self) -> Option<QBFaultKind> {
+        match self {
+            QBTypeError::Fault(fault) => Some(fault.kind()),
+            _ => None,
+        }
+    }
+}
+
+/// Fault
+///
+/// Error payload returned by `QuickBooks` Online in place of the requested entity, e.g.
+/// `{"Fault": {"Error": [{"Message": "...", "Detail": "...", "code": "5010"}], "type": "ValidationFault"}}`.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/develop/troubleshooting/error-codes>
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(default)]
+pub struct Fault {
+    /// Individual errors reported by the server
+    #[serde(rename = "Error", alias = "error")]
+    pub errors: Vec<FaultError>,
+    /// Category of the fault
+    #[serde(rename = "type")]
+    pub fault_type: Option<FaultType>,
+}
+
+impl Fault {
+    /// Classification of the first error, falling back to the fault type.
+    #[must_use]
+    pub fn kind(&self) -> QBFaultKind {
+        let kind = self
+            .errors
+            .first()
+            .map_or(QBFaultKind::Other, FaultError::kind);
+        match (kind, &self.fault_type) {
+            (QBFaultKind::Other, Some(fault_type)) => fault_type.kind(),
+            (kind, _) => kind,
+        }
+    }
+
+    /// Returns true if any error in the fault has the given classification.
+    #[must_use]
+    pub fn has_kind(&self, kind: QBFaultKind) -> bool {
+        self.kind() == kind || self.errors.iter().any(|e| e.kind() == kind)
+    }
+}
+
+impl std::fmt::Display for Fault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(fault_type) = &self.fault_type {
+            write!(f, "{fault_type:?}")?;
+        } else {
+            f.write_str("Fault")?;
+        }
+        for (i, error) in self.errors.iter().enumerate() {
+            f.write_str(if i == 0 { ": " } else { "; " })?;
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Fault {}
+
+/// `FaultError`
+///
+/// A single error entry within a [`Fault`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(default)]
+pub struct FaultError {
+    /// Short description of the error
+    #[serde(rename = "Message", alias = "message")]
+    pub message: Option<String>,
+    /// Longer description of the error
+    #[serde(rename = "Detail", alias = "detail")]
+    pub detail: Option<String>,
+    /// Numeric error code as a string, e.g. `"6240"`
+    pub code: Option<String>,
+    /// Name of the offending element, when reported
+    pub element: Option<String>,
+}
+
+impl FaultError {
+    /// Classification of this error from its code.
+    #[must_use]
+    pub fn kind(&self) -> QBFaultKind {
+        self.code
+            .as_deref()
+            .map_or(QBFaultKind::Other, QBFaultKind::from_code)
+    }
+}
+
+impl std::fmt::Display for FaultError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message.as_deref().unwrap_or("Unknown error"))?;
+        if let Some(detail) = &self.detail {
+            write!(f, " - {detail}")?;
+        }
+        if let Some(code) = &self.code {
+            write!(f, " (code {code})")?;
+        }
+        Ok(())
+    }
+}
+
+/// `FaultType` Enum
+///
+/// Category of a [`Fault`] as reported in its `type` field.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+pub enum FaultType {
+    ValidationFault,
+    SystemFault,
+    #[serde(alias = "AUTHENTICATION")]
+    AuthenticationFault,
+    AuthorizationFault,
+    ThrottlingFault,
+    #[default]
+    #[serde(other)]
+    Other,
+}
+
+impl FaultType {
+    fn kind(&self) -> QBFaultKind {
+        match self {
+            FaultType::SystemFault => QBFaultKind::System,
+            FaultType::AuthenticationFault => QBFaultKind::AuthenticationFailed,
+            FaultType::AuthorizationFault => QBFaultKind::AuthorizationFailed,
+            FaultType::ThrottlingFault => QBFaultKind::Throttled,
+            FaultType::ValidationFault | FaultType::Other => QBFaultKind::Other,
+        }
+    }
+}
+
+/// `QBFaultKind` Enum
+///
+/// Classification of common `QuickBooks` error codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum QBFaultKind {
+    /// 5010: the sync token is out of date; re-read the entity and retry
+    StaleObject,
+    /// 6240: another entity already uses this name
+    DuplicateName,
+    /// 6000: a business rule rejected the request
+    BusinessValidation,
+    /// 3001 / `ThrottlingFault`: too many requests
+    Throttled,
+    /// 3200 / `AuthenticationFault`: the access token is invalid or expired
+    AuthenticationFailed,
+    /// 3100 / `AuthorizationFault`: the app is not allowed to access this resource
+    AuthorizationFailed,
+    /// 610: the referenced object was not found or is inactive
+    ObjectNotFound,
+    /// 2020: a required parameter is missing
+    RequiredParamMissing,
+    /// 2500: a reference points to an invalid ID
+    InvalidReference,
+    /// `SystemFault`: an internal server error
+    System,
+    /// Any other code
+    Other,
+}
+
+impl QBFaultKind {
+    /// Classifies a raw error code such as `"6240"`.
+    #[must_use]
+    pub fn from_code(code: &str) -> Self {
+        match code.trim().trim_start_matches('0') {
+            "5010" => QBFaultKind::StaleObject,
+            "6240" => QBFaultKind::DuplicateName,
+            "6000" => QBFaultKind::BusinessValidation,
+            "3001" => QBFaultKind::Throttled,
+            "3200" => QBFaultKind::AuthenticationFailed,
+            "3100" => QBFaultKind::AuthorizationFailed,
+            "610" => QBFaultKind::ObjectNotFound,
+            "2020" => QBFaultKind::RequiredParamMissing,
+            "2500" => QBFaultKind::InvalidReference,
+            _ => QBFaultKind::Other,
+        }
+    }
+
+    /// Returns true for faults that may succeed if the request is simply retried later.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, QBFaultKind::Throttled | QBFaultKind::System)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_fault_payloads() {
+        let json = r#"{
+          "Error": [{
+            "Message": "Duplicate Name Exists Error",
+            "Detail": "The name supplied already exists. : Id=123",
+            "code": "6240",
+            "element": ""
+          }],
+          "type": "ValidationFault"
+        }"#;
+        let fault: Fault = serde_json::from_str(json).unwrap();
+        assert_eq!(fault.kind(), QBFaultKind::DuplicateName);
+        assert_eq!(fault.fault_type, Some(FaultType::ValidationFault));
+
+        let err = QBTypeError::from(fault);
+        assert_eq!(err.fault_kind(), Some(QBFaultKind::DuplicateName));
+        assert!(err.to_string().contains("Duplicate Name Exists Error"));
+
+        // 401 responses use lowercase keys
+        let json = r#"{
+          "error": [{ "message": "message=AuthenticationFailed", "code": "3200" }],
+          "type": "AUTHENTICATION"
+        }"#;
+        let fault: Fault = serde_json::from_str(json).unwrap();
+        assert_eq!(fault.kind(), QBFaultKind::AuthenticationFailed);
+
+        let fault = Fault {
+            fault_type: Some(FaultType::SystemFault),
+            ..Default::default()
+        };
+        assert!(fault.kind().is_retryable());
+    }
 }
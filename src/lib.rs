@@ -5,8 +5,13 @@
 /*! It does not make HTTP requests; bring your own client. */
 //!
 //! Modules and exports:
-//! - Top-level entities: `Account`, `Attachable`, `Bill`, `BillPayment`, `CompanyInfo`, `Customer`, `Employee`, `Estimate`, `Invoice`, `Item`, `Payment`, `Preferences`, `SalesReceipt`, `Vendor`
+//! - Top-level entities: `Account`, `Attachable`, `Bill`, `BillPayment`, `Budget`, `Class`, `CompanyCurrency`, `CompanyInfo`, `CreditMemo`, `Customer`, `Department`, `Deposit`, `Employee`, `Estimate`, `Exchangerate`, `InventoryAdjustment`, `Invoice`, `Item`, `JournalEntry`, `Payment`, `PaymentMethod`, `Preferences`, `Purchase`, `PurchaseOrder`, `RecurringTransaction`, `RefundReceipt`, `SalesReceipt`, `TaxAgency`, `TimeActivity`, `Transfer`, `Vendor`, `VendorCredit`
 //! - `common`: supporting types like `NtRef`, `MetaData`, addresses, phones, taxes, etc.
+//! - `query`: builder for `QuickBooks` query statements
+//! - `batch`: request/response types for the `/batch` endpoint
+//! - `cdc`: change data capture responses and a merge helper for local mirrors
+//! - `webhook`: webhook notification payloads and signature verification
+//! - `request`: sans-IO descriptors (`QBRequest`) for the calls each capability trait allows
 //! - `reports`: report models and strongly-typed parameter builders
 //!
 //! Features:
@@ -16,8 +21,8 @@
 //! Quick start (entities):
 //! ```no_run
 //! use chrono::NaiveDate;
-//! use crate::{Invoice, Line, LineDetail, SalesItemLineDetail, QBCreatable};
-//! use crate::common::NtRef;
+//! use quickbooks_types::{Invoice, Line, LineDetail, SalesItemLineDetail, QBCreatable};
+//! use quickbooks_types::common::NtRef;
 //!
 //! let invoice = Invoice {
 //!     customer_ref: Some(NtRef::from(("John Doe", "CUST-123"))),
@@ -44,8 +49,8 @@
 //! Reports parameters:
 //! ```no_run
 //! use chrono::NaiveDate;
-//! use crate::reports::types::*;
-//! use crate::reports::params::*;
+//! use quickbooks_types::reports::types::*;
+//! use quickbooks_types::reports::params::*;
 //!
 //! let params = BalanceSheetParams::new()
 //!     .accounting_method(AccountingMethod::Cash)
@@ -60,15 +65,25 @@
 #[macro_use]
 extern crate derive_builder;
 
+pub mod batch;
+pub mod cdc;
+mod entity;
 mod error;
 mod models;
+pub mod query;
 pub mod reports;
+pub mod request;
+mod validation;
+pub mod webhook;
 use std::fmt::{Debug, Display};
 
+pub use entity::*;
 pub use error::*;
 use models::common::{MetaData, NtRef};
 pub use models::*;
+use request::QBRequest;
 use serde::{de::DeserializeOwned, Serialize};
+pub use validation::{ValidationIssue, ValidationRule};
 
 /// Core trait for all `QuickBooks` entities.
 ///
@@ -119,26 +134,86 @@ pub trait QBItem: Serialize + Default + Clone + Sized + DeserializeOwned + Debug
 }
 
 /// Macro to apply a given macro to each QuickBooks entity type.
+///
+/// `for_each_qb_item!(@all func)` instead invokes `func!` once with every entity type
+/// as a comma separated list. `RecurringTransaction` (which wraps another transaction) and
+/// `Exchangerate` (which has no ID) lack the usual fields, so they are only part of the `@all`
+/// list.
 #[macro_export]
 macro_rules! for_each_qb_item {
+    (@all $func:ident) => {
+        $func!(
+            Invoice,
+            Vendor,
+            VendorCredit,
+            Payment,
+            Item,
+            JournalEntry,
+            Estimate,
+            Employee,
+            Customer,
+            CreditMemo,
+            CompanyInfo,
+            Bill,
+            Attachable,
+            Account,
+            Preferences,
+            Purchase,
+            PurchaseOrder,
+            SalesReceipt,
+            RefundReceipt,
+            BillPayment,
+            TaxCode,
+            TaxRate,
+            Term,
+            TimeActivity,
+            Class,
+            Department,
+            PaymentMethod,
+            TaxAgency,
+            RecurringTransaction,
+            Budget,
+            CompanyCurrency,
+            Exchangerate,
+            Deposit,
+            Transfer,
+            InventoryAdjustment
+        );
+    };
     ($func:ident) => {
         $func!(Invoice);
         $func!(Vendor);
+        $func!(VendorCredit);
         $func!(Payment);
         $func!(Item);
+        $func!(JournalEntry);
         $func!(Estimate);
         $func!(Employee);
         $func!(Customer);
+        $func!(CreditMemo);
         $func!(CompanyInfo);
         $func!(Bill);
         $func!(Attachable);
         $func!(Account);
         $func!(Preferences);
+        $func!(Purchase);
+        $func!(PurchaseOrder);
         $func!(SalesReceipt);
+        $func!(RefundReceipt);
         $func!(BillPayment);
         $func!(TaxCode);
         $func!(TaxRate);
         $func!(Term);
+        $func!(TimeActivity);
+        $func!(Class);
+        $func!(Department);
+        $func!(PaymentMethod);
+        $func!(TaxAgency);
+        $func!(Budget);
+        $func!(CompanyCurrency);
+        $func!(Deposit);
+        $func!(Transfer);
+        $func!(InventoryAdjustment);
     };
 }
 
@@ -208,7 +283,13 @@ for_each_qb_item!(impl_qb_data);
 ///
 /// # Required Methods
 ///
-/// - `can_create()`: Returns true if the entity has all required fields for creation
+/// - `validate_create()`: Checks every creation requirement, returning a [`ValidationIssue`]
+///   (field path, rule and message) for each one that fails
+///
+/// # Default Methods
+///
+/// - `can_create()`: Returns true if `validate_create()` passes
+/// - `create_request()`: Builds the create call as a [`QBRequest`]
 ///
 /// # Examples
 ///
@@ -225,6 +306,10 @@ for_each_qb_item!(impl_qb_data);
 ///
 /// // Now it can be created
 /// assert!(customer.can_create());
+///
+/// // Each failed requirement is reported with the field to fix
+/// let issues = quickbooks_types::Item::default().validate_create().unwrap_err();
+/// assert_eq!(issues[0].path, "Name");
 /// ```
 ///
 /// # Implementation Notes
@@ -233,9 +318,23 @@ for_each_qb_item!(impl_qb_data);
 /// - **Customer/Vendor**: Requires `display_name` or individual name components
 /// - **Account**: Requires name and `account_type` or `account_sub_type`
 /// - **Invoice**: Requires customer reference and line items
-/// - **Item**: Requires name and type
+/// - **Item**: Requires name and the accounts (or bundle components) its type needs
 pub trait QBCreatable {
-    fn can_create(&self) -> bool;
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>>;
+
+    fn can_create(&self) -> bool {
+        self.validate_create().is_ok()
+    }
+
+    /// `POST /{entity}` creating this entity; fails if
+    /// [`validate_create()`](Self::validate_create) does.
+    fn create_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError>
+    where
+        Self: QBItem,
+    {
+        request::validated::<Self>(self.validate_create(), "create")?;
+        QBRequest::post_entity::<Self>(realm_id, self, &[])
+    }
 }
 
 /// Trait for entities that can be read from `QuickBooks` by ID.
@@ -261,6 +360,12 @@ pub trait QBCreatable {
 /// ```
 pub trait QBReadable: QBItem {
     fn can_read(&self) -> bool;
+
+    /// `GET /{entity}/{id}` reading this entity; fails if [`can_read()`](Self::can_read) is false.
+    fn read_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError> {
+        request::precondition::<Self>(self.can_read(), "read")?;
+        Ok(QBRequest::read::<Self>(realm_id, request::entity_id(self)))
+    }
 }
 
 impl<T: QBItem> QBReadable for T {
@@ -274,15 +379,28 @@ impl<T: QBItem> QBReadable for T {
 /// This trait is automatically implemented for all [`QBItem`] types and indicates
 /// that the entity supports `QuickBooks` SQL-like query operations.
 ///
+/// # Default Methods
+///
+/// - `query()`: Returns a [`Query`](query::Query) builder selecting all columns of this entity
+///
 /// # Examples
 ///
 /// ```no_run
-/// use quickbooks_types::{Customer, QBQueryable};
+/// use quickbooks_types::{query::CustomerField, Customer, QBQueryable};
 ///
 /// // All QBItem types automatically implement QBQueryable
-/// let customer = Customer::default();
+/// let query = Customer::query().where_eq(CustomerField::DisplayName, "John Doe");
+/// assert_eq!(
+///     query.to_query_string(),
+///     "SELECT * FROM Customer WHERE DisplayName = 'John Doe'"
+/// );
 /// ```
-pub trait QBQueryable: QBItem {}
+pub trait QBQueryable: QBItem {
+    #[must_use]
+    fn query() -> query::Query<Self> {
+        query::Query::new()
+    }
+}
 impl<T: QBItem> QBQueryable for T {}
 
 /// Trait for entities that can be deleted from `QuickBooks`.
@@ -312,6 +430,12 @@ pub trait QBDeletable: QBItem {
     fn can_delete(&self) -> bool {
         self.has_read()
     }
+
+    /// `POST /{entity}?operation=delete`; fails if [`can_delete()`](Self::can_delete) is false.
+    fn delete_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError> {
+        request::precondition::<Self>(self.can_delete(), "delete")?;
+        QBRequest::post_reference(realm_id, self, &[("operation", "delete")])
+    }
 }
 
 /// Trait for entities that can be voided in `QuickBooks`.
@@ -344,6 +468,12 @@ pub trait QBVoidable: QBItem {
     fn can_void(&self) -> bool {
         self.has_read()
     }
+
+    /// `POST /{entity}?operation=void`; fails if [`can_void()`](Self::can_void) is false.
+    fn void_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError> {
+        request::precondition::<Self>(self.can_void(), "void")?;
+        QBRequest::post_reference(realm_id, self, &[("operation", "void")])
+    }
 }
 
 /// Trait for entities that support full update operations.
@@ -354,7 +484,13 @@ pub trait QBVoidable: QBItem {
 ///
 /// # Required Methods
 ///
-/// - `can_full_update()`: Returns true if the entity can be fully updated
+/// - `validate_update()`: Checks every full update requirement, returning a
+///   [`ValidationIssue`] for each one that fails
+///
+/// # Default Methods
+///
+/// - `can_full_update()`: Returns true if `validate_update()` passes
+/// - `full_update_request()`: Builds the update call as a [`QBRequest`]
 ///
 /// # Implementation Notes
 ///
@@ -379,7 +515,21 @@ pub trait QBVoidable: QBItem {
 /// }
 /// ```
 pub trait QBFullUpdatable {
-    fn can_full_update(&self) -> bool;
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>>;
+
+    fn can_full_update(&self) -> bool {
+        self.validate_update().is_ok()
+    }
+
+    /// `POST /{entity}` replacing this entity; fails if
+    /// [`validate_update()`](Self::validate_update) does.
+    fn full_update_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError>
+    where
+        Self: QBItem,
+    {
+        request::validated::<Self>(self.validate_update(), "full update")?;
+        QBRequest::post_entity::<Self>(realm_id, self, &[])
+    }
 }
 
 /// Trait for entities that support sparse update operations.
@@ -414,9 +564,63 @@ pub trait QBFullUpdatable {
 /// if customer.can_sparse_update() {
 ///     // Proceed with sparse update
 /// }
+///
+/// // Or send only what changed since it was read
+/// let mut renamed = customer.clone();
+/// renamed.display_name = Some("Jane Doe".to_string());
+/// let body = renamed.sparse_update_diff(&customer).unwrap();
+/// assert_eq!(body["DisplayName"], "Jane Doe");
+/// assert!(body.get("GivenName").is_none());
 /// ```
 pub trait QBSparseUpdateable {
     fn can_sparse_update(&self) -> bool;
+
+    /// `POST /{entity}` with `sparse: true`; fails if
+    /// [`can_sparse_update()`](Self::can_sparse_update) is false.
+    fn sparse_update_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError>
+    where
+        Self: QBItem,
+    {
+        request::precondition::<Self>(self.can_sparse_update(), "sparse update")?;
+        let mut request = QBRequest::post_entity::<Self>(realm_id, self, &[])?;
+        if let Some(body) = request
+            .body
+            .as_mut()
+            .and_then(serde_json::Value::as_object_mut)
+        {
+            body.insert("sparse".into(), true.into());
+        }
+        Ok(request)
+    }
+
+    /// Minimal sparse-update body turning `original` (as read) into `self`: `Id` and
+    /// `SyncToken` from `original`, `sparse: true`, and only the top-level fields whose
+    /// serialized value changed. Nested objects and `Line` are sent whole when anything
+    /// inside them changed. Fields cleared to `None` are sent as an explicit `null` so
+    /// `QuickBooks` clears them too, since a sparse update leaves omitted fields untouched.
+    ///
+    /// Fails if `original` was not read from `QuickBooks` or has a different ID.
+    fn sparse_update_diff(&self, original: &Self) -> Result<serde_json::Value, QBTypeError>
+    where
+        Self: QBItem,
+    {
+        request::sparse_diff(original, self)
+    }
+
+    /// `POST /{entity}` with the body from [`sparse_update_diff()`](Self::sparse_update_diff);
+    /// fails if [`can_sparse_update()`](Self::can_sparse_update) is false.
+    fn sparse_update_request_from(
+        &self,
+        original: &Self,
+        realm_id: &str,
+    ) -> Result<QBRequest, QBTypeError>
+    where
+        Self: QBItem,
+    {
+        request::precondition::<Self>(self.can_sparse_update(), "sparse update")?;
+        let body = self.sparse_update_diff(original)?;
+        Ok(QBRequest::new(request::Method::Post, realm_id, Self::qb_id()).json(body))
+    }
 }
 
 /// Trait for entities that can be sent via email from `QuickBooks`.
@@ -427,7 +631,22 @@ pub trait QBSparseUpdateable {
 /// # Supported Entities
 ///
 /// Typically includes: Invoice, Estimate, `SalesReceipt`, and other customer-facing documents.
-pub trait QBSendable {}
+pub trait QBSendable {
+    /// `POST /{entity}/{id}/send`, optionally overriding the recipient with `send_to`.
+    fn send_request(&self, realm_id: &str, send_to: Option<&str>) -> Result<QBRequest, QBTypeError>
+    where
+        Self: QBItem,
+    {
+        request::precondition::<Self>(self.can_read(), "send")?;
+        let resource = format!("{}/{}/send", Self::qb_id(), request::entity_id(self));
+        let mut request = QBRequest::new(request::Method::Post, realm_id, &resource);
+        if let Some(email) = send_to {
+            request = request.param("sendTo", email);
+        }
+        request.content_type = Some("application/octet-stream");
+        Ok(request)
+    }
+}
 
 /// Trait for entities that can be generated as PDF documents.
 ///
@@ -437,7 +656,19 @@ pub trait QBSendable {}
 /// # Supported Entities
 ///
 /// Typically includes: Invoice, Estimate, `SalesReceipt`, Statement, and other printable documents.
-pub trait QBPDFable {}
+pub trait QBPDFable {
+    /// `GET /{entity}/{id}/pdf`, returning the document as `application/pdf`.
+    fn pdf_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError>
+    where
+        Self: QBItem,
+    {
+        request::precondition::<Self>(self.can_read(), "pdf")?;
+        let resource = format!("{}/{}/pdf", Self::qb_id(), request::entity_id(self));
+        let mut request = QBRequest::new(request::Method::Get, realm_id, &resource);
+        request.accept = "application/pdf";
+        Ok(request)
+    }
+}
 
 /// Trait for entities that can be converted to `QuickBooks` entity references.
 ///
@@ -498,10 +729,18 @@ impl_qb_to_ref!(
     Account {
         fully_qualified_name
     },
+    Class {
+        fully_qualified_name
+    },
+    Department {
+        fully_qualified_name
+    },
     Attachable { file_name },
     Invoice { doc_number },
     SalesReceipt { doc_number },
     Item { name },
+    PaymentMethod { name },
+    TaxAgency { display_name },
     Customer { display_name },
     Vendor { display_name }
 );
@@ -515,7 +754,7 @@ Create: ✓
 - Employee
 - Estimate
 - Invoice
-- Item (Category)
+- Item (Category, Bundle)
 - Payment
 - Sales Receipt
 - Vendor
@@ -566,7 +805,7 @@ Full Update: ✓
 - Employee
 - Estimate
 - Invoice
-- Item (Category)
+- Item (Category, Bundle)
 - Payment
 - Preferences
 - Sales Receipt
@@ -4,7 +4,7 @@ use serde_with::skip_serializing_none;
 use super::common::{MetaData, NtRef};
 #[cfg(feature = "builder")]
 use crate::error::QBTypeError;
-use crate::{QBCreatable, QBFullUpdatable, QBItem};
+use crate::{validation::Validator, QBCreatable, QBFullUpdatable, ValidationIssue};
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
@@ -73,22 +73,68 @@ pub struct Account {
 /// `AccountType`
 ///
 /// High-level classification of an account (for example: Bank, `OtherAsset`, Income).
-/// Note: This enum is currently a placeholder; concrete variants will be added as the API surface is implemented.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
-#[serde(rename_all = "PascalCase")]
+/// Types not listed here deserialize as `Other`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
 pub enum AccountType {
+    Bank,
+    #[serde(rename = "Other Current Asset")]
+    OtherCurrentAsset,
+    #[serde(rename = "Fixed Asset")]
+    FixedAsset,
+    #[serde(rename = "Other Asset")]
+    OtherAsset,
+    #[serde(rename = "Accounts Receivable")]
+    AccountsReceivable,
+    Equity,
+    Expense,
+    #[serde(rename = "Other Expense")]
+    OtherExpense,
+    #[serde(rename = "Cost of Goods Sold")]
+    CostOfGoodsSold,
+    #[serde(rename = "Accounts Payable")]
+    AccountsPayable,
+    #[serde(rename = "Credit Card")]
+    CreditCard,
+    #[serde(rename = "Long Term Liability")]
+    LongTermLiability,
+    #[serde(rename = "Other Current Liability")]
+    OtherCurrentLiability,
+    Income,
+    #[serde(rename = "Other Income")]
+    OtherIncome,
     #[default]
-    TODO, // TODO: Define actual types
+    #[serde(other)]
+    Other,
+}
+
+impl AccountType {
+    /// Returns true for Accounts Receivable and Accounts Payable accounts.
+    #[must_use]
+    pub fn is_ar_ap(&self) -> bool {
+        matches!(
+            self,
+            AccountType::AccountsReceivable | AccountType::AccountsPayable
+        )
+    }
 }
 
 impl QBCreatable for Account {
-    fn can_create(&self) -> bool {
-        self.name.is_some() && (self.account_type.is_some() || self.account_sub_type.is_some())
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require("Name", &self.name)
+            .require_one_of(
+                &["AccountType", "AccountSubType"],
+                self.account_type.is_some() || self.account_sub_type.is_some(),
+            )
+            .finish()
     }
 }
 
 impl QBFullUpdatable for Account {
-    fn can_full_update(&self) -> bool {
-        self.name.is_some() && self.has_read()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .require("Name", &self.name)
+            .finish()
     }
 }
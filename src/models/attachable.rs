@@ -4,7 +4,10 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use super::common::{CustomField, MetaData, NtRef};
-use crate::{QBCreatable, QBDeletable, QBFullUpdatable, QBItem, QBToRef, QBTypeError};
+use crate::{
+    validation::Validator, QBCreatable, QBDeletable, QBFullUpdatable, QBToRef, QBTypeError,
+    ValidationIssue,
+};
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
@@ -222,15 +225,23 @@ pub trait QBToAttachableRef: QBToRef {
 impl<T: QBToRef> QBToAttachableRef for T {}
 
 impl QBCreatable for Attachable {
-    fn can_create(&self) -> bool {
-        (self.file_name.is_some() || self.note.is_some())
-            && self.content_type.is_some()
-            && self.file_path.is_some()
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_one_of(
+                &["FileName", "Note"],
+                self.file_name.is_some() || self.note.is_some(),
+            )
+            .require("ContentType", &self.content_type)
+            .require("FilePath", &self.file_path)
+            .finish()
     }
 }
 impl QBDeletable for Attachable {}
 impl QBFullUpdatable for Attachable {
-    fn can_full_update(&self) -> bool {
-        self.has_read() && self.can_create()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
     }
 }
@@ -5,7 +5,10 @@ use serde_with::skip_serializing_none;
 use super::common::{LinkedTxn, MetaData, NtRef};
 #[cfg(feature = "builder")]
 use crate::error::QBTypeError;
-use crate::{LineField, QBCreatable, QBDeletable, QBFullUpdatable, QBItem};
+use crate::{
+    validation::Validator, LineField, QBCreatable, QBDeletable, QBFullUpdatable, RecurringInfo,
+    ValidationIssue,
+};
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
@@ -72,19 +75,29 @@ pub struct Bill {
     pub department_ref: Option<NtRef>,
     /// Home currency balance for the transaction
     pub home_balance: Option<f64>,
+    /// Total amount in home currency
+    pub home_total_amt: Option<f64>,
     /// Reference to recurring schedule information
     pub recur_data_ref: Option<NtRef>,
+    /// Recurrence settings, set only on the template inside a `RecurringTransaction`
+    pub recurring_info: Option<RecurringInfo>,
 }
 
 impl QBCreatable for Bill {
-    fn can_create(&self) -> bool {
-        self.vendor_ref.is_some() && self.line.is_some()
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require("VendorRef", &self.vendor_ref)
+            .require("Line", &self.line)
+            .finish()
     }
 }
 
 impl QBDeletable for Bill {}
 impl QBFullUpdatable for Bill {
-    fn can_full_update(&self) -> bool {
-        self.has_read() && self.vendor_ref.is_some() && self.line.is_some()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
     }
 }
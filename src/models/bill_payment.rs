@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-#[cfg(feature = "builder")]
 use crate::error::QBTypeError;
 use crate::{
-    common::{MetaData, NtRef},
-    LineField, QBCreatable, QBDeletable, QBFullUpdatable, QBItem, QBVoidable,
+    common::{LinkedTxn, MetaData, NtRef},
+    request::QBRequest,
+    validation::Validator,
+    Bill, Line, LineField, QBCreatable, QBDeletable, QBFullUpdatable, QBItem, QBVoidable,
+    ValidationIssue, ValidationRule, VendorCredit,
 };
 
 #[skip_serializing_none]
@@ -21,6 +23,9 @@ use crate::{
 ///
 /// Represents a payment applied to vendor bills (accounts payable). Payments can be made by check or credit card; corresponding details are provided via `check_payment` or `credit_card_payment`.
 ///
+/// Bills and vendor credits are applied with [`BillPayment::apply_bill`] and
+/// [`BillPayment::apply_vendor_credit`], which keep `total_amt` at the net amount paid.
+///
 /// API reference:
 /// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/billpayment>
 pub struct BillPayment {
@@ -83,23 +88,272 @@ pub enum PayType {
     Check,
 }
 
-impl QBCreatable for BillPayment {
-    fn can_create(&self) -> bool {
-        self.vendor_ref.is_some()
-            && self.total_amt.is_some()
-            && self.line.is_some()
-            && self.pay_type.as_ref().is_some_and(|e| match e {
-                PayType::CreditCard => self.credit_card_payment.is_some(),
-                PayType::Check => self.check_payment.is_some(),
+impl BillPayment {
+    /// Adds a line paying `amount` of `bill` (its open `balance` if `None`) and updates `total_amt`.
+    ///
+    /// Sets `vendor_ref` from the bill if it is not set yet. Amounts already applied to the bill by
+    /// this payment's lines count against its balance.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QBTypeError::PreconditionFailed` if the bill has no ID, and
+    /// `QBTypeError::CannotApply` if it belongs to another vendor, nothing is left to pay or
+    /// `amount` is more than the open balance.
+    pub fn apply_bill(
+        &mut self,
+        bill: &Bill,
+        amount: Option<f64>,
+    ) -> Result<&mut Self, QBTypeError> {
+        self.apply(
+            bill,
+            bill.vendor_ref.as_ref(),
+            amount,
+            bill.balance.or(bill.total_amt),
+        )
+    }
+
+    /// Adds a line applying `amount` of `credit` (its open `balance` if `None`) and updates
+    /// `total_amt`, which is reduced by the credit.
+    ///
+    /// Sets `vendor_ref` from the credit if it is not set yet. Amounts already applied from the
+    /// credit by this payment's lines count against its balance.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QBTypeError::PreconditionFailed` if the credit has no ID, and
+    /// `QBTypeError::CannotApply` if it belongs to another vendor, nothing is left to apply or
+    /// `amount` is more than the open balance.
+    pub fn apply_vendor_credit(
+        &mut self,
+        credit: &VendorCredit,
+        amount: Option<f64>,
+    ) -> Result<&mut Self, QBTypeError> {
+        self.apply(
+            credit,
+            credit.vendor_ref.as_ref(),
+            amount,
+            credit.balance.or(credit.total_amt),
+        )
+    }
+
+    /// Amount paid: bill lines minus vendor credit lines.
+    #[must_use]
+    pub fn net_amount(&self) -> f64 {
+        self.line
+            .iter()
+            .flatten()
+            .map(|line| {
+                let amount = line.amount.unwrap_or_default();
+                let is_credit = line
+                    .linked_txn
+                    .iter()
+                    .flatten()
+                    .any(|txn| txn.txn_type.as_deref() == Some(VendorCredit::name()));
+                if is_credit {
+                    -amount
+                } else {
+                    amount
+                }
             })
-        // TODO Currency ref check
+            .sum()
+    }
+
+    fn apply<T: QBItem>(
+        &mut self,
+        txn: &T,
+        vendor_ref: Option<&NtRef>,
+        amount: Option<f64>,
+        open: Option<f64>,
+    ) -> Result<&mut Self, QBTypeError> {
+        let cannot_apply = |reason| QBTypeError::CannotApply {
+            credit: T::name(),
+            target: Self::name(),
+            reason,
+        };
+        let link = LinkedTxn::from_entity(txn).ok_or(QBTypeError::PreconditionFailed {
+            entity: T::name(),
+            operation: "bill payment application",
+        })?;
+        let vendor_ref = vendor_ref
+            .filter(|vendor| vendor.value.is_some())
+            .ok_or(QBTypeError::MissingField("vendor_ref"))?;
+        match &self.vendor_ref {
+            Some(paid) if paid.value != vendor_ref.value => {
+                return Err(cannot_apply("vendor does not match"));
+            }
+            Some(_) => {}
+            None => self.vendor_ref = Some(vendor_ref.clone()),
+        }
+        let applied: f64 =
+            self.line
+                .iter()
+                .flatten()
+                .filter(|line| {
+                    line.linked_txn.iter().flatten().any(|linked| {
+                        linked.txn_id == link.txn_id && linked.txn_type == link.txn_type
+                    })
+                })
+                .filter_map(|line| line.amount)
+                .sum();
+        let open = open.map(|open| open - applied);
+        if open.is_some_and(|open| open <= 0.0) {
+            return Err(cannot_apply("nothing left to apply"));
+        }
+        let amount = amount
+            .or(open)
+            .filter(|amount| *amount > 0.0)
+            .ok_or_else(|| cannot_apply("nothing left to apply"))?;
+        if open.is_some_and(|open| amount > open) {
+            return Err(cannot_apply("amount exceeds the open balance"));
+        }
+
+        self.line.get_or_insert_with(Vec::new).push(Line {
+            amount: Some(amount),
+            linked_txn: Some(vec![link]),
+            ..Default::default()
+        });
+        self.total_amt = Some(self.net_amount());
+        Ok(self)
     }
 }
 
-impl QBVoidable for BillPayment {}
+impl QBCreatable for BillPayment {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require("VendorRef", &self.vendor_ref)
+            .require("TotalAmt", &self.total_amt)
+            .require("Line", &self.line)
+            .require("PayType", &self.pay_type);
+        match self.pay_type {
+            Some(PayType::CreditCard) => {
+                v.require_because(
+                    "CreditCardPayment",
+                    &self.credit_card_payment,
+                    "when PayType is CreditCard",
+                );
+            }
+            Some(PayType::Check) => {
+                v.require_because("CheckPayment", &self.check_payment, "when PayType is Check");
+            }
+            None => {}
+        }
+        v.check(
+            self.total_amt.is_none_or(|total| total >= 0.0),
+            "TotalAmt",
+            ValidationRule::InvalidValue,
+            "TotalAmt cannot be negative; vendor credits exceed the bills paid",
+        );
+        v.finish()
+    }
+}
+
+impl QBVoidable for BillPayment {
+    fn void_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError> {
+        QBRequest::void_via_update(realm_id, self)
+    }
+}
 impl QBDeletable for BillPayment {}
 impl QBFullUpdatable for BillPayment {
-    fn can_full_update(&self) -> bool {
-        self.can_create() && self.has_read()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_bills_and_credits_at_net_amount() {
+        let bill = Bill {
+            id: Some("46".into()),
+            vendor_ref: Some(("Norton Lumber", "46").into()),
+            total_amt: Some(200.0),
+            balance: Some(150.0),
+            ..Default::default()
+        };
+        let credit = VendorCredit {
+            id: Some("52".into()),
+            vendor_ref: Some(("Norton Lumber", "46").into()),
+            balance: Some(40.0),
+            ..Default::default()
+        };
+
+        let mut payment = BillPayment {
+            pay_type: Some(PayType::Check),
+            check_payment: Some(CheckBillPayment {
+                bank_account_ref: Some(("Checking", "35").into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        payment
+            .apply_bill(&bill, None)
+            .unwrap()
+            .apply_vendor_credit(&credit, Some(25.0))
+            .unwrap();
+
+        assert_eq!(payment.vendor_ref, bill.vendor_ref);
+        assert_eq!(payment.total_amt, Some(125.0));
+        let json = serde_json::to_value(&payment).unwrap();
+        assert_eq!(
+            json["Line"],
+            serde_json::json!([
+                { "Amount": 150.0, "LinkedTxn": [{ "TxnId": "46", "TxnType": "Bill" }] },
+                { "Amount": 25.0, "LinkedTxn": [{ "TxnId": "52", "TxnType": "VendorCredit" }] },
+            ])
+        );
+        assert!(payment.can_create());
+
+        assert!(matches!(
+            payment.apply_vendor_credit(&credit, Some(40.01)),
+            Err(QBTypeError::CannotApply {
+                reason: "amount exceeds the open balance",
+                ..
+            })
+        ));
+        let paid = Bill {
+            balance: Some(0.0),
+            ..bill.clone()
+        };
+        assert!(matches!(
+            payment.apply_bill(&paid, Some(10.0)),
+            Err(QBTypeError::CannotApply {
+                reason: "nothing left to apply",
+                ..
+            })
+        ));
+        assert_eq!(payment.total_amt, Some(125.0));
+        // The bill's balance is already applied in full by the first line
+        assert!(matches!(
+            payment.apply_bill(&bill, None),
+            Err(QBTypeError::CannotApply {
+                reason: "nothing left to apply",
+                ..
+            })
+        ));
+        payment.apply_vendor_credit(&credit, Some(15.0)).unwrap();
+        assert!(matches!(
+            payment.apply_vendor_credit(&credit, Some(0.01)),
+            Err(QBTypeError::CannotApply { .. })
+        ));
+        assert_eq!(payment.total_amt, Some(110.0));
+
+        let other = VendorCredit {
+            id: Some("53".into()),
+            vendor_ref: Some(("Robertson & Associates", "49").into()),
+            balance: Some(10.0),
+            ..Default::default()
+        };
+        assert!(matches!(
+            payment.apply_vendor_credit(&other, None),
+            Err(QBTypeError::CannotApply {
+                reason: "vendor does not match",
+                ..
+            })
+        ));
     }
 }
@@ -0,0 +1,346 @@
+use chrono::{Datelike, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{MetaData, NtRef};
+use crate::error::QBTypeError;
+use crate::{reports::Report, QBItem};
+
+/// `BudgetType` Enum
+///
+/// Kind of accounts a budget plans for
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, Default)]
+pub enum BudgetType {
+    #[default]
+    ProfitAndLoss,
+    BalanceSheet,
+}
+
+/// `BudgetEntryType` Enum
+///
+/// Period covered by each `BudgetDetail` amount
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, Default)]
+pub enum BudgetEntryType {
+    #[default]
+    Monthly,
+    Quarterly,
+    Annually,
+}
+
+impl BudgetEntryType {
+    /// Number of months in one entry period.
+    #[must_use]
+    pub fn months(&self) -> u32 {
+        match self {
+            BudgetEntryType::Monthly => 1,
+            BudgetEntryType::Quarterly => 3,
+            BudgetEntryType::Annually => 12,
+        }
+    }
+}
+
+/// `BudgetDetail`
+///
+/// Budgeted amount for one account in the entry period starting at `budget_date`, optionally
+/// broken down by customer, class or department.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct BudgetDetail {
+    /// First day of the entry period
+    pub budget_date: Option<NaiveDate>,
+    /// Budgeted amount for the period
+    pub amount: Option<f64>,
+    /// Reference to the budgeted account
+    pub account_ref: Option<NtRef>,
+    /// Reference to the customer the amount is broken down by
+    pub customer_ref: Option<NtRef>,
+    /// Reference to the class the amount is broken down by
+    pub class_ref: Option<NtRef>,
+    /// Reference to the department the amount is broken down by
+    pub department_ref: Option<NtRef>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// `Budget`
+///
+/// Represents planned amounts per account over a fiscal period. Budgets are read-only through
+/// the API; compare one with actuals using [`Budget::variance`].
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/budget>
+pub struct Budget {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the entity. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Name of the budget
+    pub name: Option<String>,
+    /// Kind of accounts the budget covers
+    pub budget_type: Option<BudgetType>,
+    /// Period covered by each detail amount
+    pub budget_entry_type: Option<BudgetEntryType>,
+    /// First day of the budget
+    pub start_date: Option<NaiveDate>,
+    /// Last day of the budget
+    pub end_date: Option<NaiveDate>,
+    /// Indicates if the budget is active
+    pub active: Option<bool>,
+    /// Budgeted amounts
+    pub budget_detail: Option<Vec<BudgetDetail>>,
+}
+
+/// `BudgetVariance`
+///
+/// Budgeted and actual amounts of one account in one report period, as produced by
+/// [`Budget::variance`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BudgetVariance {
+    /// The account, named as in the report (or the budget if it had no activity)
+    pub account_ref: NtRef,
+    /// First day of the report period
+    pub period_start: NaiveDate,
+    /// Last day of the report period
+    pub period_end: NaiveDate,
+    /// Budgeted amount for the period
+    pub budgeted: f64,
+    /// Actual amount from the report
+    pub actual: f64,
+    /// `actual - budgeted`
+    pub variance: f64,
+}
+
+impl BudgetVariance {
+    /// Variance as a fraction of the budgeted amount, `None` if nothing was budgeted.
+    #[must_use]
+    pub fn variance_ratio(&self) -> Option<f64> {
+        (self.budgeted != 0.0).then(|| self.variance / self.budgeted)
+    }
+}
+
+impl Budget {
+    /// Compares this budget with a `ProfitAndLoss` report summarized by month.
+    ///
+    /// Returns one row per account and month column of the report, for every account in the
+    /// report or the budget, in report order. Quarterly and annual amounts are spread evenly over
+    /// their months, and amounts broken down by customer, class or department are summed per
+    /// account, so filter the report the same way to compare a single breakdown.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QBTypeError::ReportMismatch` if the budget is not a `ProfitAndLoss` budget, or the
+    /// report is not a `ProfitAndLoss` report with monthly columns.
+    pub fn variance(&self, report: &Report) -> Result<Vec<BudgetVariance>, QBTypeError> {
+        let mismatch = |reason| QBTypeError::ReportMismatch {
+            entity: Self::name(),
+            reason,
+        };
+        if self.budget_type == Some(BudgetType::BalanceSheet) {
+            return Err(mismatch("only ProfitAndLoss budgets can be compared"));
+        }
+        if report.name() != Some("ProfitAndLoss") {
+            return Err(mismatch("report is not a ProfitAndLoss report"));
+        }
+
+        // (report column index, period) of every column covering part of a single month
+        let months: Vec<(usize, NaiveDate, NaiveDate)> = report
+            .columns
+            .iter()
+            .flat_map(|columns| columns.column.iter().flatten())
+            .enumerate()
+            .filter_map(|(i, column)| {
+                let (start, end) = column.period()?;
+                (start.year() == end.year() && start.month() == end.month())
+                    .then_some((i, start, end))
+            })
+            .collect();
+        if months.is_empty() {
+            return Err(mismatch("report is not summarized by month"));
+        }
+
+        let mut accounts: Vec<(NtRef, Vec<f64>)> = Vec::new();
+        for row in report.leaf_rows() {
+            let Some(account) = row.first().filter(|col| col.id.is_some()) else {
+                continue;
+            };
+            let actuals = months
+                .iter()
+                .map(|&(i, ..)| {
+                    row.get(i)
+                        .and_then(|col| col.value.as_deref()?.parse().ok())
+                        .unwrap_or_default()
+                })
+                .collect();
+            accounts.push((
+                NtRef {
+                    name: account.value.clone(),
+                    value: account.id.clone(),
+                    ..Default::default()
+                },
+                actuals,
+            ));
+        }
+        for account_ref in self
+            .details()
+            .filter_map(|detail| detail.account_ref.as_ref())
+        {
+            if !accounts
+                .iter()
+                .any(|(known, _)| known.value == account_ref.value)
+            {
+                accounts.push((account_ref.clone(), vec![0.0; months.len()]));
+            }
+        }
+
+        Ok(accounts
+            .into_iter()
+            .flat_map(|(account_ref, actuals)| {
+                months
+                    .iter()
+                    .zip(actuals)
+                    .map(|(&(_, period_start, period_end), actual)| {
+                        let budgeted = self.budgeted(account_ref.value.as_deref(), period_start);
+                        BudgetVariance {
+                            account_ref: account_ref.clone(),
+                            period_start,
+                            period_end,
+                            budgeted,
+                            actual,
+                            variance: actual - budgeted,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect())
+    }
+
+    fn details(&self) -> impl Iterator<Item = &BudgetDetail> {
+        self.budget_detail.iter().flatten()
+    }
+
+    /// Amount budgeted for `account_id` in the month containing `date`.
+    fn budgeted(&self, account_id: Option<&str>, date: NaiveDate) -> f64 {
+        let months = self.budget_entry_type.unwrap_or_default().months();
+        self.details()
+            .filter(|detail| {
+                account_id.is_some()
+                    && detail.account_ref.as_ref().and_then(|r| r.value.as_deref()) == account_id
+            })
+            .filter(|detail| {
+                detail.budget_date.is_some_and(|start| {
+                    let start = start.with_day(1).unwrap_or(start);
+                    start <= date
+                        && start
+                            .checked_add_months(Months::new(months))
+                            .is_none_or(|end| date < end)
+                })
+            })
+            .filter_map(|detail| detail.amount)
+            .sum::<f64>()
+            / f64::from(months)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail(account: &str, month: u32, amount: f64) -> BudgetDetail {
+        BudgetDetail {
+            budget_date: NaiveDate::from_ymd_opt(2024, month, 1),
+            amount: Some(amount),
+            account_ref: Some(NtRef {
+                value: Some(account.into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn variance_joins_monthly_profit_and_loss() {
+        let report: Report = serde_json::from_value(serde_json::json!({
+            "Header": { "ReportName": "ProfitAndLoss", "SummarizeColumnsBy": "Month" },
+            "Columns": { "Column": [
+                { "ColTitle": "", "ColType": "Account" },
+                { "ColTitle": "Jan 2024", "ColType": "Money", "MetaData": [
+                    { "Name": "StartDate", "Value": "2024-01-01" },
+                    { "Name": "EndDate", "Value": "2024-01-31" }
+                ] },
+                { "ColTitle": "Feb 2024", "ColType": "Money", "MetaData": [
+                    { "Name": "StartDate", "Value": "2024-02-01" },
+                    { "Name": "EndDate", "Value": "2024-02-29" }
+                ] },
+                { "ColTitle": "Total", "ColType": "Money" }
+            ] },
+            "Rows": { "Row": [{
+                "Header": { "ColData": [{ "value": "Income" }, { "value": "" }, { "value": "" }, { "value": "" }] },
+                "Rows": { "Row": [{
+                    "ColData": [
+                        { "value": "Services", "id": "1" },
+                        { "value": "1200.00" },
+                        { "value": "" },
+                        { "value": "1200.00" }
+                    ],
+                    "type": "Data"
+                }] },
+                "Summary": { "ColData": [{ "value": "Total Income" }, { "value": "1200.00" }, { "value": "" }, { "value": "1200.00" }] },
+                "type": "Section"
+            }] }
+        }))
+        .unwrap();
+        let budget = Budget {
+            budget_type: Some(BudgetType::ProfitAndLoss),
+            budget_entry_type: Some(BudgetEntryType::Monthly),
+            budget_detail: Some(vec![
+                detail("1", 1, 1000.0),
+                detail("1", 2, 1000.0),
+                detail("2", 2, 50.0),
+            ]),
+            ..Default::default()
+        };
+
+        let rows = budget.variance(&report).unwrap();
+        let summary: Vec<_> = rows
+            .iter()
+            .map(|row| {
+                (
+                    row.account_ref.value.as_deref().unwrap(),
+                    row.period_start.month(),
+                    row.budgeted,
+                    row.actual,
+                    row.variance,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("1", 1, 1000.0, 1200.0, 200.0),
+                ("1", 2, 1000.0, 0.0, -1000.0),
+                ("2", 1, 0.0, 0.0, 0.0),
+                ("2", 2, 50.0, 0.0, -50.0),
+            ]
+        );
+        assert_eq!(rows[0].variance_ratio(), Some(0.2));
+
+        let quarterly = Budget {
+            budget_entry_type: Some(BudgetEntryType::Quarterly),
+            budget_detail: Some(vec![detail("1", 1, 3000.0)]),
+            ..Default::default()
+        };
+        assert_eq!(quarterly.variance(&report).unwrap()[1].budgeted, 1000.0);
+    }
+}
@@ -0,0 +1,431 @@
+use super::common::NtRef;
+use crate::{
+    validation::Validator, Bill, CreditMemo, Deposit, Entitlement, Entitlements, Estimate,
+    InventoryAdjustment, Invoice, Item, ItemType, JournalEntry, LineDetail, LineField, Payment,
+    Preferences, Purchase, PurchaseOrder, RefundReceipt, SalesReceipt, TimeActivity,
+    ValidationIssue, ValidationRule, VendorCredit,
+};
+
+/// `QBFeature` Enum
+///
+/// A feature that depends on the subscription plan and, for most, a company preference
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum QBFeature {
+    /// `ClassRef` on transactions
+    ClassTracking,
+    /// `ClassRef` on transaction lines
+    LineClassTracking,
+    /// `DepartmentRef` on transactions
+    LocationTracking,
+    /// Inventory items with quantity on hand
+    Inventory,
+    /// Transactions in a currency other than the home currency
+    MultiCurrency,
+    PurchaseOrders,
+    TimeTracking,
+}
+
+impl QBFeature {
+    /// Names the feature may have in the entitlements response.
+    #[must_use]
+    pub fn entitlement_names(&self) -> &'static [&'static str] {
+        match self {
+            QBFeature::ClassTracking | QBFeature::LineClassTracking => &["Class Tracking"],
+            QBFeature::LocationTracking => &["Location Tracking", "Department Tracking"],
+            QBFeature::Inventory => &["Inventory", "Inventory Tracking"],
+            QBFeature::MultiCurrency => &["Multi-Currency"],
+            QBFeature::PurchaseOrders => &["Purchase Orders"],
+            QBFeature::TimeTracking => &["Time Tracking"],
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            QBFeature::ClassTracking | QBFeature::LineClassTracking => "Class tracking",
+            QBFeature::LocationTracking => "Location tracking",
+            QBFeature::Inventory => "Inventory",
+            QBFeature::MultiCurrency => "Multi-currency",
+            QBFeature::PurchaseOrders => "Purchase orders",
+            QBFeature::TimeTracking => "Time tracking",
+        }
+    }
+}
+
+/// `FeatureUse`
+///
+/// A field of an entity that needs a [`QBFeature`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureUse {
+    /// Path of the field, as in [`ValidationIssue::path`]; empty for the entity itself
+    pub path: String,
+    pub feature: QBFeature,
+    /// Value of the field, e.g. the currency code of a `CurrencyRef`
+    pub value: Option<String>,
+}
+
+/// Trait for entities whose fields depend on plan features.
+pub trait QBFeatureUsage {
+    /// Every field that needs a feature to be accepted by `QuickBooks`.
+    fn features_used(&self) -> Vec<FeatureUse>;
+}
+
+/// `CapabilityProfile`
+///
+/// What a company can use: features of its plan from [`Entitlements`] and the switches in its
+/// [`Preferences`]. Either may be missing, in which case its checks are skipped.
+///
+/// ```
+/// use quickbooks_types::{CapabilityProfile, Entitlements, Item, ItemType, QBFeature};
+///
+/// let entitlements: Entitlements = serde_json::from_str(
+///     r#"{"PlanName": "ESSENTIALS", "Entitlement": [{"id": 37, "name": "Inventory", "term": "Off"}]}"#,
+/// )
+/// .unwrap();
+/// let profile = CapabilityProfile::new(entitlements, Default::default());
+/// assert!(!profile.is_available(QBFeature::Inventory));
+///
+/// let item = Item {
+///     item_type: Some(ItemType::Inventory),
+///     ..Default::default()
+/// };
+/// let issues = profile.validate(&item).unwrap_err();
+/// assert_eq!(issues[0].message, "Inventory unavailable on this plan (ESSENTIALS)");
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct CapabilityProfile {
+    pub entitlements: Option<Entitlements>,
+    pub preferences: Option<Preferences>,
+}
+
+impl CapabilityProfile {
+    #[must_use]
+    pub fn new(entitlements: Entitlements, preferences: Preferences) -> Self {
+        Self {
+            entitlements: Some(entitlements),
+            preferences: Some(preferences),
+        }
+    }
+
+    /// Whether the plan includes `feature`, `None` if the entitlements do not say.
+    #[must_use]
+    pub fn on_plan(&self, feature: QBFeature) -> Option<bool> {
+        let entitlements = self.entitlements.as_ref()?;
+        feature
+            .entitlement_names()
+            .iter()
+            .find_map(|name| entitlements.get(name))
+            .map(Entitlement::is_on)
+    }
+
+    /// Whether `feature` is turned on in preferences, `None` if it has no preference or it is
+    /// not set.
+    #[must_use]
+    pub fn enabled(&self, feature: QBFeature) -> Option<bool> {
+        let prefs = self.preferences.as_ref()?;
+        match feature {
+            QBFeature::ClassTracking => {
+                prefs.accounting_info_prefs.as_ref()?.class_tracking_per_txn
+            }
+            QBFeature::LineClassTracking => {
+                prefs
+                    .accounting_info_prefs
+                    .as_ref()?
+                    .class_tracking_per_txn_line
+            }
+            QBFeature::LocationTracking => prefs.accounting_info_prefs.as_ref()?.track_departments,
+            QBFeature::Inventory => prefs.product_and_services_prefs.as_ref()?.quantity_on_hand,
+            QBFeature::MultiCurrency => prefs.currency_prefs.as_ref()?.multi_currency_enabled,
+            QBFeature::PurchaseOrders | QBFeature::TimeTracking => None,
+        }
+    }
+
+    /// Whether `feature` can be used: neither the plan nor preferences rule it out.
+    #[must_use]
+    pub fn is_available(&self, feature: QBFeature) -> bool {
+        self.on_plan(feature) != Some(false) && self.enabled(feature) != Some(false)
+    }
+
+    /// Reports every field of `entity` that needs a feature the plan does not include or
+    /// preferences turn off. Foreign currency is only flagged for currencies other than the
+    /// home currency.
+    pub fn validate(&self, entity: &impl QBFeatureUsage) -> Result<(), Vec<ValidationIssue>> {
+        let home_currency = self
+            .preferences
+            .as_ref()
+            .and_then(Preferences::home_currency_code);
+        let mut v = Validator::new();
+        for usage in entity.features_used() {
+            let feature = usage.feature;
+            if feature == QBFeature::MultiCurrency
+                && (home_currency.is_none() || usage.value.as_deref() == home_currency)
+            {
+                continue;
+            }
+            if self.on_plan(feature) == Some(false) {
+                let plan = self
+                    .entitlements
+                    .as_ref()
+                    .and_then(|e| e.plan_name.as_deref())
+                    .unwrap_or("unknown");
+                v.check(
+                    false,
+                    &usage.path,
+                    ValidationRule::FeatureUnavailable,
+                    format!("{} unavailable on this plan ({plan})", feature.label()),
+                );
+            } else if self.enabled(feature) == Some(false) {
+                let field = usage.path.rsplit('.').next().unwrap_or_default();
+                v.check(
+                    false,
+                    &usage.path,
+                    ValidationRule::FeatureUnavailable,
+                    format!(
+                        "{field} set but {} disabled",
+                        feature.label().to_lowercase()
+                    ),
+                );
+            }
+        }
+        v.finish()
+    }
+}
+
+fn push_ref(uses: &mut Vec<FeatureUse>, path: String, feature: QBFeature, r: Option<&NtRef>) {
+    if let Some(value) = r.and_then(|r| r.value.as_ref()) {
+        uses.push(FeatureUse {
+            path,
+            feature,
+            value: Some(value.clone()),
+        });
+    }
+}
+
+fn line_features(uses: &mut Vec<FeatureUse>, lines: Option<&LineField>) {
+    for (i, line) in lines.into_iter().flatten().enumerate() {
+        let (detail, class_ref, department_ref) = match &line.line_detail {
+            LineDetail::SalesItemLineDetail(d) => {
+                ("SalesItemLineDetail", d.class_ref.as_ref(), None)
+            }
+            LineDetail::DiscountLineDetail(d) => ("DiscountLineDetail", Some(&d.class_ref), None),
+            LineDetail::ItemBasedExpenseLineDetail(d) => {
+                ("ItemBasedExpenseLineDetail", Some(&d.class_ref), None)
+            }
+            LineDetail::AccountBasedExpenseLineDetail(d) => {
+                ("AccountBasedExpenseLineDetail", Some(&d.class_ref), None)
+            }
+            LineDetail::JournalEntryLineDetail(d) => (
+                "JournalEntryLineDetail",
+                d.class_ref.as_ref(),
+                d.department_ref.as_ref(),
+            ),
+            LineDetail::DepositLineDetail(d) => ("DepositLineDetail", d.class_ref.as_ref(), None),
+            LineDetail::ItemAdjustmentLineDetail(d) => {
+                ("ItemAdjustmentLineDetail", d.class_ref.as_ref(), None)
+            }
+            _ => continue,
+        };
+        let path = |field| format!("Line[{i}].{detail}.{field}");
+        push_ref(
+            uses,
+            path("ClassRef"),
+            QBFeature::LineClassTracking,
+            class_ref,
+        );
+        push_ref(
+            uses,
+            path("DepartmentRef"),
+            QBFeature::LocationTracking,
+            department_ref,
+        );
+    }
+}
+
+macro_rules! impl_feature_usage {
+    ($($x:ident { $($field:ident),* }),+ $(,)?) => {
+        $(
+            impl QBFeatureUsage for $x {
+                fn features_used(&self) -> Vec<FeatureUse> {
+                    let mut uses = Vec::new();
+                    $(impl_feature_usage!(@field self, uses, $field);)*
+                    uses
+                }
+            }
+        )+
+    };
+    (@field $self:ident, $uses:ident, class_ref) => {
+        push_ref(&mut $uses, "ClassRef".into(), QBFeature::ClassTracking, $self.class_ref.as_ref());
+    };
+    (@field $self:ident, $uses:ident, department_ref) => {
+        push_ref(&mut $uses, "DepartmentRef".into(), QBFeature::LocationTracking, $self.department_ref.as_ref());
+    };
+    (@field $self:ident, $uses:ident, currency_ref) => {
+        push_ref(&mut $uses, "CurrencyRef".into(), QBFeature::MultiCurrency, $self.currency_ref.as_ref());
+    };
+    (@field $self:ident, $uses:ident, line) => {
+        line_features(&mut $uses, $self.line.as_ref());
+    };
+    (@field $self:ident, $uses:ident, inventory) => {
+        if $self.item_type == Some(ItemType::Inventory) {
+            $uses.push(FeatureUse { path: "Type".into(), feature: QBFeature::Inventory, value: None });
+        }
+    };
+    (@field $self:ident, $uses:ident, inventory_adjustment) => {
+        $uses.push(FeatureUse { path: String::new(), feature: QBFeature::Inventory, value: None });
+    };
+    (@field $self:ident, $uses:ident, purchase_order) => {
+        $uses.push(FeatureUse { path: String::new(), feature: QBFeature::PurchaseOrders, value: None });
+    };
+    (@field $self:ident, $uses:ident, time_activity) => {
+        $uses.push(FeatureUse { path: String::new(), feature: QBFeature::TimeTracking, value: None });
+    };
+}
+
+impl_feature_usage!(
+    Invoice {
+        class_ref,
+        department_ref,
+        currency_ref,
+        line
+    },
+    Estimate {
+        class_ref,
+        department_ref,
+        currency_ref,
+        line
+    },
+    SalesReceipt {
+        class_ref,
+        department_ref,
+        currency_ref,
+        line
+    },
+    CreditMemo {
+        class_ref,
+        department_ref,
+        currency_ref,
+        line
+    },
+    RefundReceipt {
+        class_ref,
+        department_ref,
+        currency_ref,
+        line
+    },
+    Bill {
+        department_ref,
+        currency_ref,
+        line
+    },
+    VendorCredit {
+        department_ref,
+        currency_ref,
+        line
+    },
+    Purchase {
+        department_ref,
+        currency_ref,
+        line
+    },
+    Deposit {
+        department_ref,
+        currency_ref,
+        line
+    },
+    PurchaseOrder {
+        purchase_order,
+        class_ref,
+        currency_ref,
+        line
+    },
+    JournalEntry { currency_ref, line },
+    Payment { currency_ref },
+    TimeActivity {
+        time_activity,
+        class_ref,
+        department_ref
+    },
+    Item {
+        inventory,
+        class_ref
+    },
+    InventoryAdjustment {
+        inventory_adjustment,
+        department_ref,
+        line
+    },
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AccountingInfoPrefs, CurrencyPrefs, Line, SalesItemLineDetail};
+
+    #[test]
+    fn flags_features_turned_off_in_preferences() {
+        let mut profile = CapabilityProfile {
+            entitlements: None,
+            preferences: Some(Preferences {
+                accounting_info_prefs: Some(AccountingInfoPrefs {
+                    class_tracking_per_txn: Some(false),
+                    class_tracking_per_txn_line: Some(false),
+                    ..Default::default()
+                }),
+                currency_prefs: Some(CurrencyPrefs {
+                    home_currency: Some(NtRef {
+                        value: Some("USD".into()),
+                        ..Default::default()
+                    }),
+                    multi_currency_enabled: Some(false),
+                }),
+                ..Default::default()
+            }),
+        };
+        let mut invoice = Invoice {
+            currency_ref: Some(("US Dollar", "USD").into()),
+            line: Some(vec![Line {
+                line_detail: LineDetail::SalesItemLineDetail(SalesItemLineDetail {
+                    class_ref: Some(("Retail", "3").into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let issues = profile.validate(&invoice).unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "Line[0].SalesItemLineDetail.ClassRef");
+        assert_eq!(
+            issues[0].message,
+            "ClassRef set but class tracking disabled"
+        );
+
+        invoice.currency_ref = Some(("Euro", "EUR").into());
+        let issues = profile.validate(&invoice).unwrap_err();
+        assert_eq!(issues[0].path, "CurrencyRef");
+        assert_eq!(issues.len(), 2);
+
+        // Transaction and line classes follow their own preference
+        let accounting = profile
+            .preferences
+            .as_mut()
+            .and_then(|prefs| prefs.accounting_info_prefs.as_mut())
+            .unwrap();
+        accounting.class_tracking_per_txn = Some(true);
+        invoice.currency_ref = None;
+        invoice.class_ref = Some(("Retail", "3").into());
+        let issues = profile.validate(&invoice).unwrap_err();
+        let paths: Vec<_> = issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, ["Line[0].SalesItemLineDetail.ClassRef"]);
+
+        let accounting = profile
+            .preferences
+            .as_mut()
+            .and_then(|prefs| prefs.accounting_info_prefs.as_mut())
+            .unwrap();
+        accounting.class_tracking_per_txn = Some(false);
+        accounting.class_tracking_per_txn_line = Some(true);
+        let issues = profile.validate(&invoice).unwrap_err();
+        let paths: Vec<_> = issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, ["ClassRef"]);
+    }
+}
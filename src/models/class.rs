@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{MetaData, NtRef};
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// Class
+///
+/// Represents a class used to categorize transactions and lines (e.g. by product line or location)
+/// for reporting. Classes can be nested; `fully_qualified_name` joins the path with `:`.
+/// Classes cannot be deleted, only made inactive.
+///
+/// [`build_hierarchy`](crate::build_hierarchy) assembles a list of classes into trees and fills in
+/// `fully_qualified_name` from the parent names.
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires a `name` without `:`, and a `parent_ref` when `sub_class` is true.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/class>
+pub struct Class {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the entity. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Name of the class
+    pub name: Option<String>,
+    /// Indicates the class is nested under `parent_ref`
+    pub sub_class: Option<bool>,
+    /// Reference to the parent class
+    pub parent_ref: Option<NtRef>,
+    /// Name including all parents, separated by `:`
+    pub fully_qualified_name: Option<String>,
+    /// Indicates if the class is active
+    pub active: Option<bool>,
+}
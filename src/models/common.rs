@@ -8,7 +8,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::LineField;
+use crate::{LineField, QBItem};
 
 /// `NtRef`
 ///
@@ -146,6 +146,28 @@ pub struct PhoneNumber {
 pub struct LinkedTxn {
     pub txn_id: Option<String>,
     pub txn_type: Option<String>,
+    pub txn_line_id: Option<String>,
+}
+
+impl LinkedTxn {
+    /// Link to a read `entity`, with `TxnType` set to its entity name (e.g. `"PurchaseOrder"`).
+    ///
+    /// Returns `None` if the entity has no ID.
+    #[must_use]
+    pub fn from_entity<T: QBItem>(entity: &T) -> Option<Self> {
+        Some(Self {
+            txn_id: Some(entity.clone_id()?),
+            txn_type: Some(T::name().to_owned()),
+            txn_line_id: None,
+        })
+    }
+
+    /// Narrows the link to one line of the linked transaction.
+    #[must_use]
+    pub fn with_line_id(mut self, line_id: Option<String>) -> Self {
+        self.txn_line_id = line_id;
+        self
+    }
 }
 
 /// `CustomField`
@@ -198,6 +220,20 @@ pub struct DeliveryInfo {
     pub delivery_time: DateTime<Utc>,
 }
 
+/// `CheckPayment`
+///
+/// Information about a check used to pay out a transaction (e.g. a refund).
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct CheckPayment {
+    pub check_num: Option<String>,
+    pub status: Option<String>,
+    pub name_on_acct: Option<String>,
+    pub acct_num: Option<String>,
+    pub bank_name: Option<String>,
+}
+
 /// `CreditCardPayment`
 ///
 /// Information about a credit card payment for the transaction.
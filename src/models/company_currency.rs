@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{CustomField, MetaData, NtRef};
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
+use crate::{
+    validation::Validator, QBCreatable, QBFullUpdatable, QBSparseUpdateable, ValidationIssue,
+};
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// `CompanyCurrency`
+///
+/// Represents a foreign currency enabled for a multi-currency company. Transactions reference it
+/// by currency code, not ID (see [`CompanyCurrency::currency_ref`]). Currencies cannot be
+/// deleted, only made inactive.
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires `code`.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/companycurrency>
+pub struct CompanyCurrency {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the entity. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// ISO 4217 currency code, e.g. `EUR`
+    pub code: Option<String>,
+    /// Full name of the currency
+    pub name: Option<String>,
+    /// Indicates if the currency is active
+    pub active: Option<bool>,
+    /// Custom fields for the entity
+    pub custom_field: Option<Vec<CustomField>>,
+}
+
+impl CompanyCurrency {
+    /// Reference to this currency as used in a transaction's `currency_ref`.
+    #[must_use]
+    pub fn currency_ref(&self) -> Option<NtRef> {
+        Some(NtRef {
+            name: self.name.clone(),
+            value: Some(self.code.clone()?),
+            ..Default::default()
+        })
+    }
+}
+
+impl QBCreatable for CompanyCurrency {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new().require("Code", &self.code).finish()
+    }
+}
+
+impl QBFullUpdatable for CompanyCurrency {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+impl QBSparseUpdateable for CompanyCurrency {
+    fn can_sparse_update(&self) -> bool {
+        self.can_full_update()
+    }
+}
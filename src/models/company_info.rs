@@ -5,7 +5,9 @@ use serde_with::skip_serializing_none;
 use super::common::{Addr, Email, MetaData, NtRef, PhoneNumber, WebAddr};
 #[cfg(feature = "builder")]
 use crate::error::QBTypeError;
-use crate::{QBFullUpdatable, QBSparseUpdateable};
+use crate::{
+    validation::Validator, QBFullUpdatable, QBSparseUpdateable, ValidationIssue, ValidationRule,
+};
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
@@ -66,8 +68,17 @@ pub struct CompanyInfo {
 }
 
 impl QBFullUpdatable for CompanyInfo {
-    fn can_full_update(&self) -> bool {
-        self.sync_token.is_some() && self.company_name.is_some() && self.company_addr.is_some()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .check(
+                self.sync_token.is_some(),
+                "SyncToken",
+                ValidationRule::MustBeRead,
+                "SyncToken is required; read the CompanyInfo first",
+            )
+            .require("CompanyName", &self.company_name)
+            .require("CompanyAddr", &self.company_addr)
+            .finish()
     }
 }
 
@@ -0,0 +1,238 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{
+    Addr, CustomField, Email, EmailStatus, GlobalTaxCalculation, LinkedTxn, MetaData, NtRef,
+    PrintStatus, TxnTaxDetail,
+};
+use crate::error::QBTypeError;
+use crate::{
+    request, validation::Validator, Invoice, Line, LineField, Payment, QBCreatable, QBDeletable,
+    QBFullUpdatable, QBItem, QBPDFable, QBSendable, QBSparseUpdateable, ValidationIssue,
+};
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// `CreditMemo`
+///
+/// Represents a refund or credit of payment for goods or services, reducing what the customer owes.
+/// The unused part of the credit is tracked in `remaining_credit` and can be applied to open invoices
+/// (see [`CreditMemo::apply_to_invoice`]).
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires `customer_ref` and at least one valid line.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/creditmemo>
+pub struct CreditMemo {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the transaction. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Reference to the customer being credited
+    pub customer_ref: Option<NtRef>,
+    /// Line items for the credit memo
+    pub line: Option<LineField>,
+    /// Credit not yet applied to invoices
+    pub remaining_credit: Option<f64>,
+    /// Current balance of the credit memo
+    pub balance: Option<f64>,
+    /// Total amount of the credit memo
+    pub total_amt: Option<f64>,
+    /// Total amount in home currency
+    pub home_total_amt: Option<f64>,
+    /// Date of the transaction in YYYY-MM-DD format
+    pub txn_date: Option<NaiveDate>,
+    /// Document number for the credit memo
+    pub doc_number: Option<String>,
+    /// Private note for the transaction
+    pub private_note: Option<String>,
+    /// Memo for the customer
+    pub customer_memo: Option<NtRef>,
+    /// Billing address for the credit memo
+    pub bill_addr: Option<Addr>,
+    /// Shipping address for the credit memo
+    pub ship_addr: Option<Addr>,
+    /// Email address for billing
+    pub bill_email: Option<Email>,
+    /// Email status of the credit memo
+    pub email_status: Option<EmailStatus>,
+    /// Print status of the credit memo
+    pub print_status: Option<PrintStatus>,
+    /// Tax details for the transaction
+    pub txn_tax_detail: Option<TxnTaxDetail>,
+    /// Indicates if tax is applied after discount
+    pub apply_tax_after_discount: Option<bool>,
+    /// Method in which tax is applied
+    pub global_tax_calculation: Option<GlobalTaxCalculation>,
+    /// Reference to the class for the transaction
+    pub class_ref: Option<NtRef>,
+    /// Reference to the department for the transaction
+    pub department_ref: Option<NtRef>,
+    /// Reference to the sales terms for the transaction
+    pub sales_term_ref: Option<NtRef>,
+    /// Reference to the currency for the transaction
+    pub currency_ref: Option<NtRef>,
+    /// Exchange rate for the transaction
+    pub exchange_rate: Option<f64>,
+    /// Custom fields for the entity
+    pub custom_field: Option<Vec<CustomField>>,
+}
+
+impl CreditMemo {
+    /// Builds a `Payment` that applies as much of this credit memo as possible to `invoice`.
+    ///
+    /// The payment has a zero `total_amt` and two lines for the applied amount, one linked to the
+    /// invoice and one linked to the credit memo. The amount is the smaller of the memo's
+    /// `remaining_credit` (or `total_amt` if it has not been applied yet) and the invoice's
+    /// `balance` (or `total_amt`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `QBTypeError::PreconditionFailed` if either transaction has no ID, and
+    /// `QBTypeError::CannotApply` if the customers differ or there is nothing to apply.
+    pub fn apply_to_invoice(&self, invoice: &Invoice) -> Result<Payment, QBTypeError> {
+        const OPERATION: &str = "credit application";
+        let cannot_apply = |reason| QBTypeError::CannotApply {
+            credit: Self::name(),
+            target: Invoice::name(),
+            reason,
+        };
+
+        request::precondition::<Self>(self.id.is_some(), OPERATION)?;
+        request::precondition::<Invoice>(invoice.id.is_some(), OPERATION)?;
+
+        match (&self.customer_ref, &invoice.customer_ref) {
+            (Some(credited), Some(billed))
+                if credited.value.is_some() && credited.value == billed.value => {}
+            _ => return Err(cannot_apply("customer does not match")),
+        }
+        let credit = self.remaining_credit.or(self.total_amt).unwrap_or_default();
+        if credit <= 0.0 {
+            return Err(cannot_apply("no remaining credit"));
+        }
+        let open = invoice.balance.or(invoice.total_amt).unwrap_or_default();
+        if open <= 0.0 {
+            return Err(cannot_apply("invoice has no open balance"));
+        }
+
+        let amount = credit.min(open);
+        let line = |link: Option<LinkedTxn>| Line {
+            amount: Some(amount),
+            linked_txn: link.map(|link| vec![link]),
+            ..Default::default()
+        };
+        Ok(Payment {
+            customer_ref: invoice.customer_ref.clone(),
+            currency_ref: invoice
+                .currency_ref
+                .clone()
+                .or_else(|| self.currency_ref.clone()),
+            total_amt: Some(0.0),
+            line: Some(vec![
+                line(LinkedTxn::from_entity(invoice)),
+                line(LinkedTxn::from_entity(self)),
+            ]),
+            ..Default::default()
+        })
+    }
+}
+
+impl QBCreatable for CreditMemo {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require("CustomerRef", &self.customer_ref)
+            .nested("Line", self.line.validate_create())
+            .finish()
+    }
+}
+
+impl QBDeletable for CreditMemo {}
+
+impl QBFullUpdatable for CreditMemo {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+impl QBSparseUpdateable for CreditMemo {
+    fn can_sparse_update(&self) -> bool {
+        self.can_full_update()
+    }
+}
+
+impl QBSendable for CreditMemo {}
+impl QBPDFable for CreditMemo {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_to_invoice_links_both_transactions() {
+        let credit_memo = CreditMemo {
+            id: Some("73".into()),
+            customer_ref: Some(("Amy's Bird Sanctuary", "1").into()),
+            total_amt: Some(50.0),
+            remaining_credit: Some(35.0),
+            ..Default::default()
+        };
+        let mut invoice = Invoice {
+            id: Some("130".into()),
+            customer_ref: Some(("Amy's Bird Sanctuary", "1").into()),
+            total_amt: Some(100.0),
+            balance: Some(20.0),
+            currency_ref: Some(("Euro", "EUR").into()),
+            ..Default::default()
+        };
+
+        let payment = credit_memo.apply_to_invoice(&invoice).unwrap();
+        assert!(payment.can_create());
+        assert_eq!(
+            payment
+                .currency_ref
+                .as_ref()
+                .and_then(|c| c.value.as_deref()),
+            Some("EUR")
+        );
+        assert_eq!(payment.total_amt, Some(0.0));
+        let json = serde_json::to_value(&payment).unwrap();
+        assert_eq!(
+            json["Line"],
+            serde_json::json!([
+                { "Amount": 20.0, "LinkedTxn": [{ "TxnId": "130", "TxnType": "Invoice" }] },
+                { "Amount": 20.0, "LinkedTxn": [{ "TxnId": "73", "TxnType": "CreditMemo" }] },
+            ])
+        );
+
+        invoice.customer_ref = Some(("Bill's Windsurf Shop", "2").into());
+        assert!(matches!(
+            credit_memo.apply_to_invoice(&invoice),
+            Err(QBTypeError::CannotApply {
+                reason: "customer does not match",
+                ..
+            })
+        ));
+    }
+}
@@ -5,7 +5,9 @@ use serde_with::skip_serializing_none;
 use super::common::{Addr, Email, MetaData, NtRef, PhoneNumber, WebAddr};
 #[cfg(feature = "builder")]
 use crate::error::QBTypeError;
-use crate::{QBCreatable, QBFullUpdatable, QBReadable, QBSparseUpdateable};
+use crate::{
+    validation::Validator, QBCreatable, QBFullUpdatable, QBSparseUpdateable, ValidationIssue,
+};
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
@@ -220,19 +222,34 @@ impl From<TaxExemptStatus> for u8 {
 }
 
 impl QBCreatable for Customer {
-    fn can_create(&self) -> bool {
-        self.display_name.is_some()
-            || self.suffix.is_some()
-            || self.title.is_some()
-            || self.middle_name.is_some()
-            || self.family_name.is_some()
-            || self.given_name.is_some()
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_one_of(
+                &[
+                    "DisplayName",
+                    "Title",
+                    "GivenName",
+                    "MiddleName",
+                    "FamilyName",
+                    "Suffix",
+                ],
+                self.display_name.is_some()
+                    || self.suffix.is_some()
+                    || self.title.is_some()
+                    || self.middle_name.is_some()
+                    || self.family_name.is_some()
+                    || self.given_name.is_some(),
+            )
+            .finish()
     }
 }
 
 impl QBFullUpdatable for Customer {
-    fn can_full_update(&self) -> bool {
-        self.can_read() && self.can_create()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
     }
 }
 
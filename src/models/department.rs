@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{MetaData, NtRef};
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// Department
+///
+/// Represents a department (or location) used to track transactions by business segment
+/// for reporting. Departments can be nested; `fully_qualified_name` joins the path with `:`.
+/// Departments cannot be deleted, only made inactive.
+///
+/// Queried departments can be nested back into trees with
+/// [`build_hierarchy`](crate::build_hierarchy), which also recomputes `fully_qualified_name`.
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires a `name` without `:`, and a `parent_ref` when `sub_department` is true.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/department>
+pub struct Department {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the entity. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Name of the department
+    pub name: Option<String>,
+    /// Indicates the department is nested under `parent_ref`
+    pub sub_department: Option<bool>,
+    /// Reference to the parent department
+    pub parent_ref: Option<NtRef>,
+    /// Name including all parents, separated by `:`
+    pub fully_qualified_name: Option<String>,
+    /// Indicates if the department is active
+    pub active: Option<bool>,
+}
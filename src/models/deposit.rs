@@ -0,0 +1,286 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{CustomField, GlobalTaxCalculation, LinkedTxn, MetaData, NtRef, TxnTaxDetail};
+use crate::error::QBTypeError;
+use crate::{
+    validation::Validator, Line, LineDetail, LineField, Payment, QBCreatable, QBDeletable,
+    QBFullUpdatable, QBItem, QBSparseUpdateable, RefundReceipt, SalesReceipt, ValidationIssue,
+    ValidationRule,
+};
+
+/// `CashBack`
+///
+/// Part of a deposit taken back as cash instead of being deposited.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct CashBack {
+    pub account_ref: Option<NtRef>,
+    pub amount: Option<f64>,
+    pub memo: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// Deposit
+///
+/// Represents a bank deposit. Lines either move received payments out of Undeposited Funds
+/// (no line detail, `LinkedTxn` to the payment; see [`Deposit::add_undeposited`]) or record funds
+/// deposited directly with a `DepositLineDetail`.
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires `deposit_to_account_ref` and at least one line.
+/// - Every line needs an `Amount`, and either a `DepositLineDetail` with `AccountRef` or a `LinkedTxn`.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/deposit>
+pub struct Deposit {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the transaction. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Bank account the funds are deposited to
+    pub deposit_to_account_ref: Option<NtRef>,
+    /// Deposited payments and direct deposit lines
+    pub line: Option<LineField>,
+    /// Total amount of the deposit
+    pub total_amt: Option<f64>,
+    /// Total amount in home currency
+    pub home_total_amt: Option<f64>,
+    /// Cash taken back from the deposit
+    pub cash_back: Option<CashBack>,
+    /// Date of the transaction in YYYY-MM-DD format
+    pub txn_date: Option<NaiveDate>,
+    /// Private note for the transaction
+    pub private_note: Option<String>,
+    /// Reference to the department for the transaction
+    pub department_ref: Option<NtRef>,
+    /// Reference to the currency for the transaction
+    pub currency_ref: Option<NtRef>,
+    /// Exchange rate for the transaction
+    pub exchange_rate: Option<f64>,
+    /// Tax details for the transaction
+    pub txn_tax_detail: Option<TxnTaxDetail>,
+    /// Method in which tax is applied
+    pub global_tax_calculation: Option<GlobalTaxCalculation>,
+    /// Source of the transaction
+    pub txn_source: Option<String>,
+    /// Custom fields for the entity
+    pub custom_field: Option<Vec<CustomField>>,
+}
+
+/// Transactions whose funds can be held in Undeposited Funds until they are deposited.
+pub trait Depositable: QBItem {
+    /// Total amount of the transaction available to deposit.
+    fn deposit_amount(&self) -> Option<f64>;
+}
+
+macro_rules! impl_depositable {
+    ($($x:ident),+) => {
+        $(
+            impl Depositable for $x {
+                fn deposit_amount(&self) -> Option<f64> {
+                    self.total_amt
+                }
+            }
+        )+
+    };
+}
+
+impl_depositable!(Payment, SalesReceipt, RefundReceipt);
+
+impl Deposit {
+    /// Adds a line depositing `amount` of `txn` (a `Payment`, `SalesReceipt` or `RefundReceipt`
+    /// held in Undeposited Funds) and updates `total_amt` to the sum of the lines.
+    ///
+    /// The line is linked to the transaction with `TxnLineId` `"0"`, as `QuickBooks` expects for
+    /// deposited transactions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QBTypeError::PreconditionFailed` if `txn` has no ID, and
+    /// `QBTypeError::CannotApply` if `amount` is not positive or, together with what this deposit
+    /// already takes from `txn`, exceeds its `total_amt`.
+    pub fn add_undeposited<T: Depositable>(
+        &mut self,
+        txn: &T,
+        amount: f64,
+    ) -> Result<&mut Self, QBTypeError> {
+        let link = LinkedTxn::from_entity(txn)
+            .ok_or(QBTypeError::PreconditionFailed {
+                entity: T::name(),
+                operation: "deposit",
+            })?
+            .with_line_id(Some("0".into()));
+        let cannot_apply = |reason| QBTypeError::CannotApply {
+            credit: T::name(),
+            target: Self::name(),
+            reason,
+        };
+        if amount <= 0.0 {
+            return Err(cannot_apply("amount must be positive"));
+        }
+        let deposited: f64 = self
+            .line
+            .iter()
+            .flatten()
+            .filter(|line| {
+                line.linked_txn
+                    .iter()
+                    .flatten()
+                    .any(|linked| linked == &link)
+            })
+            .filter_map(|line| line.amount)
+            .sum();
+        if txn
+            .deposit_amount()
+            .is_some_and(|total| deposited + amount > total)
+        {
+            return Err(cannot_apply("amount exceeds the transaction total"));
+        }
+        let line = self.line.get_or_insert_with(Vec::new);
+        line.push(Line {
+            amount: Some(amount),
+            linked_txn: Some(vec![link]),
+            ..Default::default()
+        });
+        self.total_amt = Some(line.iter().filter_map(|line| line.amount).sum());
+        Ok(self)
+    }
+}
+
+impl QBCreatable for Deposit {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require("DepositToAccountRef", &self.deposit_to_account_ref)
+            .check(
+                self.line.as_ref().is_some_and(|line| !line.is_empty()),
+                "Line",
+                ValidationRule::Required,
+                "at least one line is required",
+            );
+        for (i, line) in self.line.iter().flatten().enumerate() {
+            let path = format!("Line[{i}]");
+            v.require(&format!("{path}.Amount"), &line.amount);
+            match &line.line_detail {
+                LineDetail::DepositLineDetail(detail) => {
+                    v.require(
+                        &format!("{path}.DepositLineDetail.AccountRef"),
+                        &detail.account_ref,
+                    );
+                }
+                LineDetail::None => {
+                    v.require_because(
+                        &format!("{path}.LinkedTxn"),
+                        &line.linked_txn,
+                        "when the line has no DepositLineDetail",
+                    );
+                }
+                _ => {
+                    v.check(
+                        false,
+                        &format!("{path}.DetailType"),
+                        ValidationRule::InvalidValue,
+                        "deposit lines must use DepositLineDetail or link a transaction",
+                    );
+                }
+            }
+        }
+        v.finish()
+    }
+}
+
+impl QBDeletable for Deposit {}
+
+impl QBFullUpdatable for Deposit {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+impl QBSparseUpdateable for Deposit {
+    fn can_sparse_update(&self) -> bool {
+        self.can_full_update()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DepositLineDetail;
+
+    #[test]
+    fn deposit_clears_undeposited_payments() {
+        let payment = Payment {
+            id: Some("159".into()),
+            total_amt: Some(75.0),
+            ..Default::default()
+        };
+        let mut deposit = Deposit {
+            deposit_to_account_ref: Some(("Checking", "35").into()),
+            ..Default::default()
+        };
+        for amount in [0.0, -50.0, 75.01] {
+            assert!(matches!(
+                deposit.add_undeposited(&payment, amount),
+                Err(QBTypeError::CannotApply { .. })
+            ));
+        }
+        deposit.add_undeposited(&payment, 75.0).unwrap();
+        assert!(matches!(
+            deposit.add_undeposited(&payment, 1.0),
+            Err(QBTypeError::CannotApply {
+                reason: "amount exceeds the transaction total",
+                ..
+            })
+        ));
+        deposit.line.as_mut().unwrap().push(Line {
+            amount: Some(20.0),
+            line_detail: LineDetail::DepositLineDetail(DepositLineDetail {
+                account_ref: Some(("Interest Earned", "82").into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        deposit.line.as_mut().unwrap().push(Line {
+            amount: Some(5.0),
+            ..Default::default()
+        });
+
+        let issues = deposit.validate_create().unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "Line[2].LinkedTxn");
+
+        deposit.line.as_mut().unwrap().pop();
+        assert!(deposit.can_create());
+        assert_eq!(deposit.total_amt, Some(75.0));
+        let json = serde_json::to_value(&deposit).unwrap();
+        assert_eq!(
+            json["Line"][0]["LinkedTxn"],
+            serde_json::json!([{ "TxnId": "159", "TxnType": "Payment", "TxnLineId": "0" }])
+        );
+        assert_eq!(json["Line"][1]["DetailType"], "DepositLineDetail");
+    }
+}
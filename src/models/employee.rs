@@ -5,7 +5,7 @@ use serde_with::skip_serializing_none;
 use super::common::{Addr, Email, MetaData, PhoneNumber};
 #[cfg(feature = "builder")]
 use crate::error::QBTypeError;
-use crate::{QBCreatable, QBFullUpdatable, QBItem};
+use crate::{validation::Validator, QBCreatable, QBFullUpdatable, ValidationIssue};
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
@@ -83,13 +83,21 @@ pub struct Employee {
 }
 
 impl QBCreatable for Employee {
-    fn can_create(&self) -> bool {
-        self.given_name.is_some() || self.family_name.is_some()
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_one_of(
+                &["GivenName", "FamilyName"],
+                self.given_name.is_some() || self.family_name.is_some(),
+            )
+            .finish()
     }
 }
 
 impl QBFullUpdatable for Employee {
-    fn can_full_update(&self) -> bool {
-        self.has_read() && self.can_create()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
     }
 }
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr, PickFirst};
+
+use crate::request::{Method, QBRequest};
+
+/// `Entitlements`
+///
+/// The company's subscription plan and the features it includes, returned by the
+/// `/manage/entitlements/v3/{realm_id}` endpoint (see [`Entitlements::request`]). Combine it with
+/// `Preferences` in a [`CapabilityProfile`](crate::CapabilityProfile) to check entities against
+/// what the company can actually use.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/develop/authentication-and-authorization/entitlements-api>
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct Entitlements {
+    /// Indicates if the company is a `QuickBooks` Online company
+    pub qbo_company: Option<bool>,
+    /// Subscription plan, e.g. `PLUS` or `ADVANCED`
+    pub plan_name: Option<String>,
+    /// Number of users the plan allows
+    pub max_users: Option<u32>,
+    /// Number of users the company has
+    pub current_users: Option<u32>,
+    /// Days left in a trial subscription
+    pub days_remaining_trial: Option<u32>,
+    /// Features of the plan and whether each is included
+    pub entitlement: Option<Vec<Entitlement>>,
+}
+
+/// `Entitlement`
+///
+/// One feature of a subscription plan, e.g. `Class Tracking` with term `On`.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(default)]
+pub struct Entitlement {
+    /// Numeric ID of the feature
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    pub id: Option<u32>,
+    /// Name of the feature
+    pub name: Option<String>,
+    /// `On` if the plan includes the feature, `Off` otherwise
+    pub term: Option<String>,
+}
+
+impl Entitlement {
+    /// Whether the plan includes the feature.
+    #[must_use]
+    pub fn is_on(&self) -> bool {
+        self.term
+            .as_deref()
+            .is_some_and(|term| term.eq_ignore_ascii_case("on"))
+    }
+}
+
+impl Entitlements {
+    /// `GET /manage/entitlements/v3/{realm_id}`, served from the same host as the accounting API.
+    #[must_use]
+    pub fn request(realm_id: &str) -> QBRequest {
+        QBRequest {
+            method: Method::Get,
+            path: format!("/manage/entitlements/v3/{realm_id}"),
+            query: Vec::new(),
+            body: None,
+            content_type: None,
+            accept: "application/json",
+        }
+    }
+
+    /// Looks up a feature by name, ignoring case, spaces and punctuation
+    /// (so `"Multi-Currency"` matches `"multicurrency"`).
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Entitlement> {
+        fn normalize(name: &str) -> impl Iterator<Item = char> + '_ {
+            name.chars()
+                .filter(char::is_ascii_alphanumeric)
+                .map(|c| c.to_ascii_lowercase())
+        }
+        self.entitlement.iter().flatten().find(|e| {
+            e.name
+                .as_deref()
+                .is_some_and(|n| normalize(n).eq(normalize(name)))
+        })
+    }
+}
@@ -3,11 +3,11 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use super::common::{Addr, CustomField, Email, LinkedTxn, MetaData, NtRef, TxnTaxDetail};
-#[cfg(feature = "builder")]
 use crate::error::QBTypeError;
 use crate::{
-    common::EmailStatus, LineField, QBCreatable, QBDeletable, QBFullUpdatable, QBItem, QBPDFable,
-    QBSendable, QBSparseUpdateable,
+    common::EmailStatus, request, validation::Validator, Invoice, Line, LineDetail, LineField,
+    Preferences, QBCreatable, QBDeletable, QBFullUpdatable, QBItem, QBPDFable, QBSendable,
+    QBSparseUpdateable, RecurringInfo, SalesReceipt, ValidationIssue, ValidationRule,
 };
 
 #[skip_serializing_none]
@@ -22,7 +22,8 @@ use crate::{
 /// Estimate
 ///
 /// Represents a proposal for a financial transaction between a business and its customer.
-/// It outlines proposed goods or services and their costs, which may later become an invoice.
+/// It outlines proposed goods or services and their costs, which may later become an invoice
+/// (see [`Estimate::to_invoice`] and, for partial billing, [`Estimate::to_progress_invoice`]).
 ///
 /// Creation requirements:
 /// - `QBCreatable::can_create()` returns true when both `customer_ref` and at least one valid line are present.
@@ -106,6 +107,8 @@ pub struct Estimate {
     pub total_amt: Option<f64>,
     /// Reference to recurring data for the estimate
     pub recur_data_ref: Option<NtRef>,
+    /// Recurrence settings, set only on the template inside a `RecurringTransaction`
+    pub recurring_info: Option<RecurringInfo>,
     /// Reference to tax exemption information
     pub tax_exemption_ref: Option<NtRef>,
     /// Total amount in home currency
@@ -114,23 +117,370 @@ pub struct Estimate {
     pub free_form_address: Option<bool>,
 }
 
+/// `ProgressPortion` Enum
+///
+/// Part of an estimate billed by [`Estimate::to_progress_invoice`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProgressPortion {
+    /// Percentage (0-100] of every item and bundle line's estimated amount, capped at what remains
+    Percent(f64),
+    /// Everything that remains on the item and bundle lines with these IDs
+    Lines(Vec<String>),
+}
+
+/// `EstimateLineProgress`
+///
+/// How much of one estimate item line has been invoiced, as returned by [`Estimate::progress`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct EstimateLineProgress {
+    /// ID of the estimate line
+    pub line_id: String,
+    /// Amount of the estimate line
+    pub estimated: f64,
+    /// Sum of the invoice lines linked to the estimate line
+    pub invoiced: f64,
+    /// `estimated - invoiced`, never negative
+    pub remaining: f64,
+}
+
+fn round_cents(amount: f64) -> f64 {
+    (amount * 100.0).round() / 100.0
+}
+
+/// Scales the quantities of an item or group line, and the amounts of a group's lines, by `ratio`.
+fn scale_line(line: &mut Line, ratio: f64) {
+    match &mut line.line_detail {
+        LineDetail::SalesItemLineDetail(detail) => {
+            detail.qty = detail.qty.map(|qty| qty * ratio);
+        }
+        LineDetail::GroupLineDetail(detail) => {
+            detail.quantity *= ratio;
+            for component in &mut detail.line {
+                component.amount = component.amount.map(|amount| round_cents(amount * ratio));
+                scale_line(component, ratio);
+            }
+        }
+        _ => {}
+    }
+}
+
+impl Estimate {
+    /// Builds an `Invoice` for the whole estimate.
+    ///
+    /// Customer, currency, addresses, terms, memo, custom fields, lines and tax detail are copied.
+    /// The invoice is linked to the estimate (`TxnType = "Estimate"`), and each copied line with an
+    /// ID is linked to its estimate line. Dates and doc number are left for the caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QBTypeError::PreconditionFailed` if the estimate has not been read from
+    /// `QuickBooks` or is closed or rejected, and `QBTypeError::MissingField` if it has no
+    /// customer or lines.
+    pub fn to_invoice(&self) -> Result<Invoice, QBTypeError> {
+        let (link, line) = self.converted_lines("conversion to Invoice")?;
+        Ok(self.invoice_with(link, line, self.txn_tax_detail.clone()))
+    }
+
+    /// Builds a `SalesReceipt` for the whole estimate, copying the same fields as
+    /// [`to_invoice()`](Self::to_invoice).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`to_invoice()`](Self::to_invoice).
+    pub fn to_sales_receipt(&self) -> Result<SalesReceipt, QBTypeError> {
+        let (link, line) = self.converted_lines("conversion to SalesReceipt")?;
+        Ok(SalesReceipt {
+            customer_ref: self.customer_ref.clone(),
+            currency_ref: self.currency_ref.clone(),
+            exchange_rate: self.exchange_rate,
+            bill_email: self.bill_email.clone(),
+            bill_addr: self.bill_addr.clone(),
+            ship_addr: self.ship_addr.clone(),
+            ship_from_addr: self.ship_from_addr.clone(),
+            ship_date: self.ship_date,
+            ship_method_ref: self.ship_method_ref.clone(),
+            free_form_address: self.free_form_address,
+            class_ref: self.class_ref.clone(),
+            department_ref: self.department_ref.clone(),
+            customer_memo: self.customer_memo.clone(),
+            custom_field: self.custom_field.clone(),
+            apply_tax_after_discount: self.apply_tax_after_discount,
+            txn_tax_detail: self.txn_tax_detail.clone(),
+            total_amt: self.total_amt,
+            linked_txn: Some(vec![link]),
+            line: Some(line),
+            ..Default::default()
+        })
+    }
+
+    /// Invoiced and remaining amounts of each item line, from the lines of `invoices` linked to
+    /// this estimate (see [`to_progress_invoice()`](Self::to_progress_invoice)).
+    ///
+    /// Only item (`SalesItemLineDetail`) and bundle (`GroupLineDetail`) lines with an ID and amount
+    /// are tracked.
+    #[must_use]
+    pub fn progress(&self, invoices: &[Invoice]) -> Vec<EstimateLineProgress> {
+        let invoiced_lines: Vec<_> = invoices
+            .iter()
+            .flat_map(|invoice| invoice.line.iter().flatten())
+            .filter_map(|line| {
+                let amount = line.amount?;
+                let link = line.linked_txn.iter().flatten().find(|link| {
+                    self.id.is_some()
+                        && link.txn_id == self.id
+                        && link.txn_type.as_deref() == Some(Self::name())
+                })?;
+                Some((link.txn_line_id.as_deref()?, amount))
+            })
+            .collect();
+        self.line
+            .iter()
+            .flatten()
+            .filter(|line| {
+                matches!(
+                    line.line_detail,
+                    LineDetail::SalesItemLineDetail(_) | LineDetail::GroupLineDetail(_)
+                )
+            })
+            .filter_map(|line| {
+                let line_id = line.id.clone()?;
+                let estimated = line.amount?;
+                let invoiced = round_cents(
+                    invoiced_lines
+                        .iter()
+                        .filter(|(id, _)| *id == line_id)
+                        .map(|(_, amount)| amount)
+                        .sum(),
+                );
+                Some(EstimateLineProgress {
+                    remaining: round_cents(estimated - invoiced).max(0.0),
+                    line_id,
+                    estimated,
+                    invoiced,
+                })
+            })
+            .collect()
+    }
+
+    /// Builds an `Invoice` for part of the estimate, given the invoices already created from it.
+    ///
+    /// Each item or bundle line is billed for its share of `portion`, never more than its
+    /// [`remaining`](EstimateLineProgress::remaining) amount; quantities are scaled to match and
+    /// every line is linked to its estimate line so later calls can track progress. Discount lines
+    /// are prorated by the share of the item amounts billed, subtotal lines are recomputed and
+    /// description lines are kept. Only the tax code is copied from the tax detail, as `QuickBooks`
+    /// recalculates tax for the partial amounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QBTypeError::ValidationFailed` if progress invoicing is not turned on in
+    /// `preferences` (`SalesFormsPrefs.UsingProgressInvoicing`), the percentage is outside
+    /// (0, 100], or a selected line is not an item line of the estimate, and
+    /// `QBTypeError::MissingField` if nothing remains to be invoiced. Otherwise the same as
+    /// [`to_invoice()`](Self::to_invoice).
+    pub fn to_progress_invoice(
+        &self,
+        portion: &ProgressPortion,
+        invoices: &[Invoice],
+        preferences: &Preferences,
+    ) -> Result<Invoice, QBTypeError> {
+        let progress = self.progress(invoices);
+        let mut v = Validator::new();
+        v.check(
+            preferences
+                .sales_forms_prefs
+                .as_ref()
+                .and_then(|prefs| prefs.using_progress_invoicing)
+                == Some(true),
+            "SalesFormsPrefs.UsingProgressInvoicing",
+            ValidationRule::FeatureUnavailable,
+            "progress invoicing is turned off",
+        );
+        match portion {
+            ProgressPortion::Percent(percent) => {
+                v.check(
+                    *percent > 0.0 && *percent <= 100.0,
+                    "Percent",
+                    ValidationRule::InvalidValue,
+                    "Percent must be greater than 0 and at most 100",
+                );
+            }
+            ProgressPortion::Lines(ids) => {
+                for (i, id) in ids.iter().enumerate() {
+                    v.check(
+                        progress.iter().any(|line| line.line_id == *id),
+                        &format!("Lines[{i}]"),
+                        ValidationRule::InvalidValue,
+                        format!("{id} is not an item line of this estimate"),
+                    );
+                }
+            }
+        }
+        request::validated::<Self>(v.finish(), "progress invoicing")?;
+
+        let (link, lines) = self.converted_lines("conversion to Invoice")?;
+        let billed: Vec<(&str, f64, f64)> = progress
+            .iter()
+            .map(|p| {
+                let amount = match portion {
+                    ProgressPortion::Percent(percent) => {
+                        round_cents(p.estimated * percent / 100.0).min(p.remaining)
+                    }
+                    ProgressPortion::Lines(ids) if ids.contains(&p.line_id) => p.remaining,
+                    ProgressPortion::Lines(_) => 0.0,
+                };
+                (p.line_id.as_str(), p.estimated, amount)
+            })
+            .collect();
+        let estimated_total: f64 = billed.iter().map(|(_, estimated, _)| estimated).sum();
+        let billed_total: f64 = billed.iter().map(|(.., amount)| amount).sum();
+        if billed_total <= 0.0 {
+            return Err(QBTypeError::MissingField("line"));
+        }
+        let portion_ratio = if estimated_total == 0.0 {
+            0.0
+        } else {
+            billed_total / estimated_total
+        };
+
+        let mut subtotal = 0.0;
+        let mut total = 0.0;
+        let mut line = LineField::new();
+        for mut next in lines {
+            match &next.line_detail {
+                LineDetail::SalesItemLineDetail(_) | LineDetail::GroupLineDetail(_) => {
+                    let line_id = next
+                        .linked_txn
+                        .iter()
+                        .flatten()
+                        .find_map(|link| link.txn_line_id.as_deref());
+                    let Some(&(_, estimated, amount)) =
+                        billed.iter().find(|(id, ..)| Some(*id) == line_id)
+                    else {
+                        continue;
+                    };
+                    if amount <= 0.0 {
+                        continue;
+                    }
+                    if estimated != 0.0 {
+                        scale_line(&mut next, amount / estimated);
+                    }
+                    next.amount = Some(amount);
+                    subtotal += amount;
+                    total += amount;
+                }
+                LineDetail::DiscountLineDetail(_) => {
+                    let amount = round_cents(next.amount.unwrap_or_default() * portion_ratio);
+                    next.amount = Some(amount);
+                    total -= amount;
+                }
+                LineDetail::SubTotalLineDetail(_) => {
+                    next.amount = Some(round_cents(subtotal));
+                    subtotal = 0.0;
+                }
+                _ => {}
+            }
+            line.push(next);
+        }
+
+        let txn_tax_detail = self.txn_tax_detail.as_ref().map(|detail| TxnTaxDetail {
+            txn_tax_code_ref: detail.txn_tax_code_ref.clone(),
+            ..Default::default()
+        });
+        let mut invoice = self.invoice_with(link, line, txn_tax_detail);
+        invoice.total_amt = Some(round_cents(total));
+        Ok(invoice)
+    }
+
+    /// Checks the conversion preconditions and copies the lines, linking each line with an ID to
+    /// its estimate line.
+    fn converted_lines(
+        &self,
+        operation: &'static str,
+    ) -> Result<(LinkedTxn, LineField), QBTypeError> {
+        request::precondition::<Self>(
+            self.has_read() && !matches!(self.txn_status.as_deref(), Some("Closed" | "Rejected")),
+            operation,
+        )?;
+        if self.customer_ref.is_none() {
+            return Err(QBTypeError::MissingField("customer_ref"));
+        }
+        let link = LinkedTxn::from_entity(self).ok_or(QBTypeError::MissingField("id"))?;
+        let line: LineField = self
+            .line
+            .iter()
+            .flatten()
+            .map(|line| {
+                let mut line = line.clone();
+                if let Some(id) = line.id.take() {
+                    line.linked_txn = Some(vec![link.clone().with_line_id(Some(id))]);
+                }
+                line
+            })
+            .collect();
+        if line.is_empty() {
+            return Err(QBTypeError::MissingField("line"));
+        }
+        Ok((link, line))
+    }
+
+    fn invoice_with(
+        &self,
+        link: LinkedTxn,
+        line: LineField,
+        txn_tax_detail: Option<TxnTaxDetail>,
+    ) -> Invoice {
+        Invoice {
+            customer_ref: self.customer_ref.clone(),
+            currency_ref: self.currency_ref.clone(),
+            exchange_rate: self.exchange_rate,
+            bill_email: self.bill_email.clone(),
+            bill_addr: self.bill_addr.clone(),
+            ship_addr: self.ship_addr.clone(),
+            ship_from_addr: self.ship_from_addr.clone(),
+            ship_date: self.ship_date,
+            ship_method_reef: self.ship_method_ref.clone(),
+            free_form_address: self.free_form_address,
+            class_ref: self.class_ref.clone(),
+            department_ref: self.department_ref.clone(),
+            sales_term_ref: self.sales_term_ref.clone(),
+            customer_memo: self.customer_memo.clone(),
+            custom_field: self.custom_field.clone(),
+            apply_tax_after_discount: self.apply_tax_after_discount,
+            tax_exemption_ref: self.tax_exemption_ref.clone(),
+            txn_tax_detail,
+            total_amt: self.total_amt,
+            linked_txn: Some(vec![link]),
+            line: Some(line),
+            ..Default::default()
+        }
+    }
+}
+
 impl QBCreatable for Estimate {
-    fn can_create(&self) -> bool {
-        self.line.is_some() && self.customer_ref.is_some()
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require("CustomerRef", &self.customer_ref)
+            .require("Line", &self.line)
+            .finish()
     }
 }
 
 impl QBDeletable for Estimate {}
 
 impl QBFullUpdatable for Estimate {
-    fn can_full_update(&self) -> bool {
-        if !self.has_read() || self.customer_ref.is_none() {
-            false
-        } else if let Some(EmailStatus::NeedToSend) = self.email_status.as_ref() {
-            self.bill_email.is_some()
-        } else {
-            true
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require_read(self)
+            .require("CustomerRef", &self.customer_ref);
+        if let Some(EmailStatus::NeedToSend) = self.email_status.as_ref() {
+            v.require_because(
+                "BillEmail",
+                &self.bill_email,
+                "when EmailStatus is NeedToSend",
+            );
         }
+        v.finish()
     }
 }
 
@@ -142,3 +492,160 @@ impl QBSparseUpdateable for Estimate {
 
 impl QBSendable for Estimate {}
 impl QBPDFable for Estimate {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Line, SalesFormsPrefs, SalesItemLineDetail};
+
+    fn item_line(id: &str, qty: f64, unit_price: f64) -> Line {
+        Line {
+            id: Some(id.into()),
+            amount: Some(qty * unit_price),
+            line_detail: LineDetail::SalesItemLineDetail(SalesItemLineDetail {
+                item_ref: Some(("Pump", "11").into()),
+                qty: Some(qty),
+                unit_price: Some(unit_price),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn estimate() -> Estimate {
+        Estimate {
+            id: Some("41".into()),
+            sync_token: Some("0".into()),
+            customer_ref: Some(("Geeta Kalapatapu", "10").into()),
+            txn_status: Some("Accepted".into()),
+            line: Some(vec![item_line("1", 4.0, 25.0), item_line("2", 1.0, 50.0)]),
+            total_amt: Some(150.0),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn to_invoice_links_the_estimate_and_its_lines() {
+        let invoice = estimate().to_invoice().unwrap();
+        assert!(invoice.can_create());
+        let json = serde_json::to_value(&invoice).unwrap();
+        assert_eq!(
+            json["LinkedTxn"],
+            serde_json::json!([{ "TxnId": "41", "TxnType": "Estimate" }])
+        );
+        assert_eq!(
+            json["Line"][1]["LinkedTxn"],
+            serde_json::json!([{ "TxnId": "41", "TxnType": "Estimate", "TxnLineId": "2" }])
+        );
+        assert_eq!(
+            estimate().to_sales_receipt().unwrap().total_amt,
+            Some(150.0)
+        );
+
+        let rejected = Estimate {
+            txn_status: Some("Rejected".into()),
+            ..estimate()
+        };
+        assert!(matches!(
+            rejected.to_invoice(),
+            Err(QBTypeError::PreconditionFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn progress_invoices_track_remaining_amounts() {
+        let estimate = estimate();
+        let preferences = Preferences {
+            sales_forms_prefs: Some(SalesFormsPrefs {
+                using_progress_invoicing: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let first = estimate
+            .to_progress_invoice(&ProgressPortion::Percent(75.0), &[], &preferences)
+            .unwrap();
+        assert_eq!(first.total_amt, Some(112.5));
+        let LineDetail::SalesItemLineDetail(detail) = &first.line.as_ref().unwrap()[0].line_detail
+        else {
+            panic!("expected a sales item line");
+        };
+        assert_eq!(detail.qty, Some(3.0));
+
+        let progress = estimate.progress(std::slice::from_ref(&first));
+        assert_eq!(
+            progress.iter().map(|p| p.remaining).collect::<Vec<_>>(),
+            [25.0, 12.5]
+        );
+
+        let second = estimate
+            .to_progress_invoice(
+                &ProgressPortion::Percent(50.0),
+                std::slice::from_ref(&first),
+                &preferences,
+            )
+            .unwrap();
+        assert_eq!(second.total_amt, Some(37.5));
+        let rest = estimate
+            .to_progress_invoice(
+                &ProgressPortion::Lines(vec!["2".into()]),
+                &[first, second],
+                &preferences,
+            )
+            .unwrap_err();
+        assert!(matches!(rest, QBTypeError::MissingField("line")));
+
+        let issues = match estimate.to_progress_invoice(
+            &ProgressPortion::Lines(vec!["9".into()]),
+            &[],
+            &Preferences::default(),
+        ) {
+            Err(QBTypeError::ValidationFailed { issues, .. }) => issues,
+            other => panic!("expected validation failure, got {other:?}"),
+        };
+        let paths: Vec<_> = issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            ["SalesFormsPrefs.UsingProgressInvoicing", "Lines[0]"]
+        );
+    }
+
+    #[test]
+    fn progress_invoices_keep_discount_and_subtotal_lines() {
+        let mut estimate = estimate();
+        let lines = estimate.line.as_mut().unwrap();
+        lines.push(Line {
+            amount: Some(150.0),
+            line_detail: LineDetail::SubTotalLineDetail(Default::default()),
+            ..Default::default()
+        });
+        lines.push(Line {
+            id: Some("3".into()),
+            amount: Some(15.0),
+            line_detail: LineDetail::DiscountLineDetail(Default::default()),
+            ..Default::default()
+        });
+        estimate.total_amt = Some(135.0);
+        let preferences = Preferences {
+            sales_forms_prefs: Some(SalesFormsPrefs {
+                using_progress_invoicing: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let full = estimate
+            .to_progress_invoice(&ProgressPortion::Percent(100.0), &[], &preferences)
+            .unwrap();
+        assert_eq!(full.total_amt, estimate.to_invoice().unwrap().total_amt);
+        assert_eq!(full.line.as_ref().map(Vec::len), Some(4));
+
+        let half = estimate
+            .to_progress_invoice(&ProgressPortion::Lines(vec!["2".into()]), &[], &preferences)
+            .unwrap();
+        let amounts: Vec<_> = half.line.iter().flatten().map(|l| l.amount).collect();
+        assert_eq!(amounts, [Some(50.0), Some(50.0), Some(5.0)]);
+        assert_eq!(half.total_amt, Some(45.0));
+    }
+}
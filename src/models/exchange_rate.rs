@@ -0,0 +1,121 @@
+use std::fmt::Display;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{CustomField, MetaData};
+use crate::{
+    request::{Method, QBRequest},
+    validation::Validator,
+    QBFullUpdatable, QBItem, ValidationIssue, ValidationRule,
+};
+
+/// `Exchangerate`
+///
+/// Represents the rate converting one unit of `source_currency_code` into the home currency
+/// (`target_currency_code`) as of a date. Rates have no ID: they are read by currency and date
+/// (see [`Exchangerate::rate_request`]) and updated by posting a new `rate` for the same pair
+/// and date.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `sync_token`, `source_currency_code`,
+///   `as_of_date` and a positive `rate`.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/exchangerate>
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct Exchangerate {
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the entity. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Currency code being converted, e.g. `EUR`
+    pub source_currency_code: Option<String>,
+    /// Currency code converted to; always the home currency
+    pub target_currency_code: Option<String>,
+    /// Units of the target currency per unit of the source currency
+    pub rate: Option<f64>,
+    /// Date the rate applies from
+    pub as_of_date: Option<NaiveDate>,
+    /// Custom fields for the entity
+    pub custom_field: Option<Vec<CustomField>>,
+}
+
+impl Exchangerate {
+    /// `GET /exchangerate` for `source_currency_code` as of `as_of_date` (today if `None`).
+    #[must_use]
+    pub fn rate_request(
+        realm_id: &str,
+        source_currency_code: &str,
+        as_of_date: Option<NaiveDate>,
+    ) -> QBRequest {
+        let request = QBRequest::new(Method::Get, realm_id, Self::qb_id())
+            .param("sourcecurrencycode", source_currency_code);
+        match as_of_date {
+            Some(date) => request.param("asofdate", date.to_string()),
+            None => request,
+        }
+    }
+}
+
+impl QBItem for Exchangerate {
+    fn id(&self) -> Option<&String> {
+        None
+    }
+
+    fn clone_id(&self) -> Option<String> {
+        None
+    }
+
+    fn sync_token(&self) -> Option<&String> {
+        self.sync_token.as_ref()
+    }
+
+    fn meta_data(&self) -> Option<&MetaData> {
+        self.meta_data.as_ref()
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        "Exchangerate"
+    }
+
+    #[inline]
+    fn qb_id() -> &'static str {
+        "exchangerate"
+    }
+}
+
+impl Display for Exchangerate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} : {}",
+            Self::name(),
+            serde_json::to_string_pretty(self).expect("Could not serialize object for display!")
+        )
+    }
+}
+
+impl QBFullUpdatable for Exchangerate {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require("SyncToken", &self.sync_token)
+            .require("SourceCurrencyCode", &self.source_currency_code)
+            .require("AsOfDate", &self.as_of_date)
+            .require("Rate", &self.rate)
+            .check(
+                self.rate.is_none_or(|rate| rate > 0.0),
+                "Rate",
+                ValidationRule::InvalidValue,
+                "Rate must be positive",
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+use crate::{
+    validation::Validator, Class, Department, QBCreatable, QBFullUpdatable, QBItem,
+    QBSparseUpdateable, ValidationIssue, ValidationRule,
+};
+
+/// Entities that nest under a parent of the same type through `ParentRef`.
+pub trait Hierarchical: QBItem {
+    /// Own name, without parents.
+    fn node_name(&self) -> Option<&str>;
+    /// ID of the parent, if nested.
+    fn parent_id(&self) -> Option<&str>;
+    /// Sets the `:`-separated name including all parents.
+    fn set_fully_qualified_name(&mut self, name: String);
+}
+
+/// Implements [`Hierarchical`] and the create/update validation shared by nested list entities.
+/// `$sub` is the flag field marking the entity as nested, and `$sub_name` its API name.
+macro_rules! impl_hierarchical {
+    ($($x:ident: $sub:ident $sub_name:literal),+ $(,)?) => {
+        $(
+            impl Hierarchical for $x {
+                fn node_name(&self) -> Option<&str> {
+                    self.name.as_deref()
+                }
+
+                fn parent_id(&self) -> Option<&str> {
+                    self.parent_ref.as_ref().and_then(|parent| parent.value.as_deref())
+                }
+
+                fn set_fully_qualified_name(&mut self, name: String) {
+                    self.fully_qualified_name = Some(name);
+                }
+            }
+
+            impl QBCreatable for $x {
+                fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+                    let mut v = Validator::new();
+                    v.require("Name", &self.name).check(
+                        self.name.as_ref().is_none_or(|name| !name.contains(':')),
+                        "Name",
+                        ValidationRule::InvalidValue,
+                        "Name cannot contain ':'",
+                    );
+                    if self.$sub == Some(true) {
+                        v.require_because(
+                            "ParentRef",
+                            &self.parent_ref,
+                            concat!("when ", $sub_name, " is true"),
+                        );
+                    }
+                    v.finish()
+                }
+            }
+
+            impl QBFullUpdatable for $x {
+                fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+                    Validator::new()
+                        .require_read(self)
+                        .nested("", self.validate_create())
+                        .finish()
+                }
+            }
+
+            impl QBSparseUpdateable for $x {
+                fn can_sparse_update(&self) -> bool {
+                    self.can_full_update()
+                }
+            }
+        )+
+    };
+}
+
+impl_hierarchical!(Class: sub_class "SubClass", Department: sub_department "SubDepartment");
+
+/// `HierarchyNode`
+///
+/// One entity in a tree built by [`build_hierarchy`], with its direct children.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HierarchyNode<T> {
+    pub item: T,
+    pub children: Vec<HierarchyNode<T>>,
+}
+
+impl<T> HierarchyNode<T> {
+    /// Visits this node and all of its descendants depth-first.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(&node.item)
+        })
+    }
+}
+
+/// Assembles a flat list (e.g. a `Class` query result) into trees, in input order.
+///
+/// Each entity's `fully_qualified_name` is recomputed from the names of its ancestors
+/// (`Parent:Child`). Entities whose parent is not in the list, or that are part of a `ParentRef`
+/// cycle, become roots.
+pub fn build_hierarchy<T: Hierarchical>(items: Vec<T>) -> Vec<HierarchyNode<T>> {
+    let index: HashMap<String, usize> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(i, item)| Some((item.clone_id()?, i)))
+        .collect();
+    let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut roots = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        match item.parent_id().and_then(|parent| index.get(parent)) {
+            Some(&parent) if parent != i => children.entry(parent).or_default().push(i),
+            _ => roots.push(i),
+        }
+    }
+
+    let mut slots: Vec<Option<T>> = items.into_iter().map(Some).collect();
+    let mut trees: Vec<_> = roots
+        .into_iter()
+        .filter_map(|i| assemble(i, None, &mut slots, &children))
+        .collect();
+    // Whatever is left is only reachable through a cycle.
+    for i in 0..slots.len() {
+        if let Some(node) = assemble(i, None, &mut slots, &children) {
+            trees.push(node);
+        }
+    }
+    trees
+}
+
+fn assemble<T: Hierarchical>(
+    i: usize,
+    parent_name: Option<&str>,
+    slots: &mut [Option<T>],
+    children: &HashMap<usize, Vec<usize>>,
+) -> Option<HierarchyNode<T>> {
+    let mut item = slots[i].take()?;
+    let name = item.node_name().unwrap_or_default();
+    let full_name = match parent_name {
+        Some(parent) => format!("{parent}:{name}"),
+        None => name.to_owned(),
+    };
+    let children = children
+        .get(&i)
+        .into_iter()
+        .flatten()
+        .filter_map(|&child| assemble(child, Some(&full_name), slots, children))
+        .collect();
+    item.set_fully_qualified_name(full_name);
+    Some(HierarchyNode { item, children })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn class(id: &str, name: &str, parent: Option<&str>) -> Class {
+        Class {
+            id: Some(id.into()),
+            name: Some(name.into()),
+            sub_class: Some(parent.is_some()),
+            parent_ref: parent.map(Into::into),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn builds_trees_with_qualified_names() {
+        let trees = build_hierarchy(vec![
+            class("3", "Retail", Some("1")),
+            class("1", "Sales", None),
+            class("4", "Online", Some("3")),
+            class("2", "Services", None),
+            class("5", "Loop A", Some("6")),
+            class("6", "Loop B", Some("5")),
+        ]);
+
+        let names: Vec<Vec<_>> = trees
+            .iter()
+            .map(|tree| {
+                tree.iter()
+                    .map(|class| class.fully_qualified_name.as_deref().unwrap())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            names,
+            [
+                vec!["Sales", "Sales:Retail", "Sales:Retail:Online"],
+                vec!["Services"],
+                vec!["Loop A", "Loop A:Loop B"],
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_entities_need_a_parent() {
+        let department = Department {
+            name: Some("East:Coast".into()),
+            sub_department: Some(true),
+            ..Default::default()
+        };
+        let issues = department.validate_create().unwrap_err();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[1].path, "ParentRef");
+        assert_eq!(
+            issues[1].message,
+            "ParentRef is required when SubDepartment is true"
+        );
+
+        assert!(class("2", "Retail", Some("1")).validate_create().is_ok());
+    }
+}
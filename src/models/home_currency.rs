@@ -0,0 +1,249 @@
+use super::common::NtRef;
+use crate::{
+    validation::Validator, Bill, Exchangerate, Invoice, Preferences, QBItem, QBTypeError,
+    SalesReceipt, ValidationIssue, ValidationRule,
+};
+
+/// Home amounts within half a cent of the recomputed value are considered correct.
+const TOLERANCE: f64 = 0.005;
+
+/// `HomeAmounts`
+///
+/// A transaction's `total_amt` and `balance` converted to the home currency.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct HomeAmounts {
+    pub total_amt: Option<f64>,
+    pub balance: Option<f64>,
+}
+
+/// Checks a transaction's currency and exchange rate against the company's home currency.
+fn validate_currency(
+    currency_ref: Option<&NtRef>,
+    exchange_rate: Option<f64>,
+    preferences: &Preferences,
+) -> Result<(), Vec<ValidationIssue>> {
+    let mut v = Validator::new();
+    let Some(home) = preferences.home_currency_code() else {
+        return v
+            .check(
+                false,
+                "CurrencyRef",
+                ValidationRule::InvalidValue,
+                "Preferences has no CurrencyPrefs.HomeCurrency to convert to",
+            )
+            .finish();
+    };
+    match currency_ref.and_then(|currency| currency.value.as_deref()) {
+        Some(currency) if currency != home => {
+            let multi_currency = preferences
+                .currency_prefs
+                .as_ref()
+                .and_then(|prefs| prefs.multi_currency_enabled);
+            v.check(
+                multi_currency != Some(false),
+                "CurrencyRef",
+                ValidationRule::InvalidValue,
+                format!("{currency} is not the home currency ({home}) and multi-currency is off"),
+            )
+            .require_because(
+                "ExchangeRate",
+                &exchange_rate,
+                "when CurrencyRef is not the home currency",
+            )
+            .check(
+                exchange_rate.is_none_or(|rate| rate > 0.0),
+                "ExchangeRate",
+                ValidationRule::InvalidValue,
+                "ExchangeRate must be positive",
+            );
+        }
+        _ => {
+            v.check(
+                exchange_rate.is_none_or(|rate| (rate - 1.0).abs() < f64::EPSILON),
+                "ExchangeRate",
+                ValidationRule::InvalidValue,
+                format!("ExchangeRate must be 1 for transactions in the home currency ({home})"),
+            );
+        }
+    }
+    v.finish()
+}
+
+fn to_home(amount: Option<f64>, exchange_rate: Option<f64>) -> Option<f64> {
+    amount.map(|amount| (amount * exchange_rate.unwrap_or(1.0) * 100.0).round() / 100.0)
+}
+
+macro_rules! impl_home_currency {
+    ($($x:ident),+) => {
+        $(
+            impl $x {
+                /// `total_amt` and `balance` converted to the home currency at `exchange_rate`,
+                /// rounded to cents.
+                ///
+                /// # Errors
+                ///
+                /// Returns `QBTypeError::ValidationFailed` if `currency_ref` and `exchange_rate`
+                /// are inconsistent with the home currency in `preferences`.
+                pub fn home_amounts(&self, preferences: &Preferences) -> Result<HomeAmounts, QBTypeError> {
+                    validate_currency(self.currency_ref.as_ref(), self.exchange_rate, preferences)
+                        .map_err(|issues| QBTypeError::ValidationFailed {
+                            entity: Self::name(),
+                            operation: "home currency conversion",
+                            issues,
+                        })?;
+                    Ok(HomeAmounts {
+                        total_amt: to_home(self.total_amt, self.exchange_rate),
+                        balance: to_home(self.balance, self.exchange_rate),
+                    })
+                }
+
+                /// Sets `home_total_amt` and `home_balance` from [`home_amounts()`](Self::home_amounts).
+                ///
+                /// # Errors
+                ///
+                /// Same as [`home_amounts()`](Self::home_amounts); nothing is changed on error.
+                pub fn recompute_home_amounts(&mut self, preferences: &Preferences) -> Result<(), QBTypeError> {
+                    let home = self.home_amounts(preferences)?;
+                    self.home_total_amt = home.total_amt;
+                    self.home_balance = home.balance;
+                    Ok(())
+                }
+
+                /// Sets `exchange_rate` from `rate` and recomputes the home amounts.
+                ///
+                /// # Errors
+                ///
+                /// Returns `QBTypeError::CurrencyMismatch` unless `rate` converts this transaction's
+                /// currency into the home currency, otherwise the same as
+                /// [`home_amounts()`](Self::home_amounts).
+                pub fn apply_exchange_rate(
+                    &mut self,
+                    rate: &Exchangerate,
+                    preferences: &Preferences,
+                ) -> Result<(), QBTypeError> {
+                    let currency = self.currency_ref.as_ref().and_then(|c| c.value.as_deref());
+                    let home = preferences.home_currency_code();
+                    if rate.source_currency_code.is_none()
+                        || rate.source_currency_code.as_deref() != currency
+                        || rate.target_currency_code.as_deref() != home
+                    {
+                        let pair = |from: Option<&str>, to: Option<&str>| {
+                            format!("{} to {}", from.unwrap_or("?"), to.unwrap_or("?"))
+                        };
+                        return Err(QBTypeError::CurrencyMismatch {
+                            expected: pair(currency, home),
+                            found: pair(
+                                rate.source_currency_code.as_deref(),
+                                rate.target_currency_code.as_deref(),
+                            ),
+                        });
+                    }
+                    let previous = self.exchange_rate.replace(rate.rate.unwrap_or_default());
+                    self.recompute_home_amounts(preferences).inspect_err(|_| {
+                        self.exchange_rate = previous;
+                    })
+                }
+
+                /// Checks the currency against `preferences` and that `home_total_amt` /
+                /// `home_balance`, when present, match the recomputed amounts.
+                pub fn validate_home_amounts(&self, preferences: &Preferences) -> Result<(), Vec<ValidationIssue>> {
+                    validate_currency(self.currency_ref.as_ref(), self.exchange_rate, preferences)?;
+                    let home = HomeAmounts {
+                        total_amt: to_home(self.total_amt, self.exchange_rate),
+                        balance: to_home(self.balance, self.exchange_rate),
+                    };
+                    let mut v = Validator::new();
+                    for (path, stored, expected) in [
+                        ("HomeTotalAmt", self.home_total_amt, home.total_amt),
+                        ("HomeBalance", self.home_balance, home.balance),
+                    ] {
+                        if let (Some(stored), Some(expected)) = (stored, expected) {
+                            v.check(
+                                (stored - expected).abs() < TOLERANCE,
+                                path,
+                                ValidationRule::InvalidValue,
+                                format!("{path} ({stored}) does not match the converted amount ({expected})"),
+                            );
+                        }
+                    }
+                    v.finish()
+                }
+            }
+        )+
+    };
+}
+
+impl_home_currency!(Invoice, Bill, SalesReceipt);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CurrencyPrefs;
+
+    fn preferences() -> Preferences {
+        Preferences {
+            currency_prefs: Some(CurrencyPrefs {
+                home_currency: Some(NtRef {
+                    value: Some("USD".into()),
+                    ..Default::default()
+                }),
+                multi_currency_enabled: Some(true),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn recomputes_and_checks_home_amounts() {
+        let mut invoice = Invoice {
+            currency_ref: Some(("Euro", "EUR").into()),
+            exchange_rate: Some(1.1),
+            total_amt: Some(100.0),
+            balance: Some(40.0),
+            home_total_amt: Some(100.0),
+            ..Default::default()
+        };
+        let issues = invoice.validate_home_amounts(&preferences()).unwrap_err();
+        assert_eq!(issues[0].path, "HomeTotalAmt");
+
+        invoice.recompute_home_amounts(&preferences()).unwrap();
+        assert_eq!(
+            (invoice.home_total_amt, invoice.home_balance),
+            (Some(110.0), Some(44.0))
+        );
+        assert!(invoice.validate_home_amounts(&preferences()).is_ok());
+
+        let rate = Exchangerate {
+            source_currency_code: Some("EUR".into()),
+            target_currency_code: Some("USD".into()),
+            rate: Some(1.2),
+            ..Default::default()
+        };
+        invoice.apply_exchange_rate(&rate, &preferences()).unwrap();
+        assert_eq!(invoice.home_total_amt, Some(120.0));
+        let gbp = Exchangerate {
+            source_currency_code: Some("GBP".into()),
+            ..rate
+        };
+        let err = invoice
+            .apply_exchange_rate(&gbp, &preferences())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Exchange rate GBP to USD does not convert EUR to USD"
+        );
+        assert_eq!(invoice.exchange_rate, Some(1.2));
+
+        let bill = Bill {
+            exchange_rate: Some(1.2),
+            total_amt: Some(10.0),
+            ..Default::default()
+        };
+        let issues = bill.validate_home_amounts(&preferences()).unwrap_err();
+        assert_eq!(issues[0].path, "ExchangeRate");
+        assert!(matches!(
+            SalesReceipt::default().home_amounts(&Preferences::default()),
+            Err(QBTypeError::ValidationFailed { .. })
+        ));
+    }
+}
@@ -0,0 +1,183 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{MetaData, NtRef};
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
+use crate::{
+    validation::Validator, Item, ItemAdjustmentLineDetail, ItemType, Line, LineDetail, LineField,
+    QBCreatable, QBDeletable, ValidationIssue, ValidationRule,
+};
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// `InventoryAdjustment`
+///
+/// Represents a change in the quantity on hand of one or more inventory items (e.g. shrinkage or
+/// a stock count correction), offset against the adjustment account.
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires `adjust_account_ref` and at least one line, each an
+///   `ItemAdjustmentLineDetail` with an `ItemRef` and a non-zero `QtyDiff`.
+/// - [`InventoryAdjustment::validate_items`] additionally checks that the referenced items are
+///   inventory items.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/inventoryadjustment>
+pub struct InventoryAdjustment {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the transaction. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Account the value of the adjusted stock is posted to (e.g. Inventory Shrinkage)
+    pub adjust_account_ref: Option<NtRef>,
+    /// Quantity adjustment lines
+    pub line: Option<LineField>,
+    /// Date of the transaction in YYYY-MM-DD format
+    pub txn_date: Option<NaiveDate>,
+    /// Document number for the adjustment
+    pub doc_number: Option<String>,
+    /// Private note for the transaction
+    pub private_note: Option<String>,
+    /// Reference to the department for the transaction
+    pub department_ref: Option<NtRef>,
+}
+
+impl InventoryAdjustment {
+    /// Appends a line changing the quantity on hand of `item_ref` by `qty_diff`.
+    #[must_use]
+    pub fn with_line(mut self, item_ref: impl Into<NtRef>, qty_diff: f64) -> Self {
+        self.line.get_or_insert_with(Vec::new).push(Line {
+            line_detail: LineDetail::ItemAdjustmentLineDetail(ItemAdjustmentLineDetail {
+                item_ref: Some(item_ref.into()),
+                qty_diff: Some(qty_diff),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Checks that every line references an inventory item among `items`.
+    ///
+    /// Lines whose item is not in `items` are skipped, so pass every item the adjustment refers to.
+    pub fn validate_items(&self, items: &[Item]) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        for (i, line) in self.line.iter().flatten().enumerate() {
+            let LineDetail::ItemAdjustmentLineDetail(detail) = &line.line_detail else {
+                continue;
+            };
+            let id = detail.item_ref.as_ref().and_then(|r| r.value.as_ref());
+            if let Some(item) = items
+                .iter()
+                .find(|item| id.is_some() && item.id.as_ref() == id)
+            {
+                v.check(
+                    item.item_type == Some(ItemType::Inventory),
+                    &format!("Line[{i}].ItemAdjustmentLineDetail.ItemRef"),
+                    ValidationRule::InvalidValue,
+                    format!(
+                        "{} is not an inventory item",
+                        item.name.as_deref().unwrap_or("item")
+                    ),
+                );
+            }
+        }
+        v.finish()
+    }
+}
+
+impl QBCreatable for InventoryAdjustment {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let lines = self.line.as_deref().unwrap_or_default();
+        let mut v = Validator::new();
+        v.require("AdjustAccountRef", &self.adjust_account_ref)
+            .check(
+                !lines.is_empty(),
+                "Line",
+                ValidationRule::Required,
+                "at least one line is required",
+            );
+        for (i, line) in lines.iter().enumerate() {
+            let LineDetail::ItemAdjustmentLineDetail(detail) = &line.line_detail else {
+                v.check(
+                    false,
+                    &format!("Line[{i}].DetailType"),
+                    ValidationRule::InvalidValue,
+                    "DetailType must be ItemAdjustmentLineDetail",
+                );
+                continue;
+            };
+            let path = format!("Line[{i}].ItemAdjustmentLineDetail.QtyDiff");
+            v.require(
+                &format!("Line[{i}].ItemAdjustmentLineDetail.ItemRef"),
+                &detail.item_ref,
+            )
+            .require(&path, &detail.qty_diff)
+            .check(
+                detail.qty_diff.is_none_or(|qty| qty != 0.0),
+                &path,
+                ValidationRule::InvalidValue,
+                "QtyDiff must not be zero",
+            );
+        }
+        v.finish()
+    }
+}
+
+impl QBDeletable for InventoryAdjustment {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_lines_and_item_types() {
+        let adjustment = InventoryAdjustment {
+            adjust_account_ref: Some(("Inventory Shrinkage", "55").into()),
+            ..Default::default()
+        }
+        .with_line(("Pump", "11"), -2.0)
+        .with_line(("Design", "4"), 0.0);
+
+        let issues = adjustment.validate_create().unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "Line[1].ItemAdjustmentLineDetail.QtyDiff");
+
+        let items = [
+            Item {
+                id: Some("11".into()),
+                item_type: Some(ItemType::Inventory),
+                ..Default::default()
+            },
+            Item {
+                id: Some("4".into()),
+                name: Some("Design".into()),
+                item_type: Some(ItemType::Service),
+                ..Default::default()
+            },
+        ];
+        let issues = adjustment.validate_items(&items).unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "Line[1].ItemAdjustmentLineDetail.ItemRef");
+
+        let json = serde_json::to_value(&adjustment).unwrap();
+        assert_eq!(json["Line"][0]["DetailType"], "ItemAdjustmentLineDetail");
+        assert_eq!(json["Line"][0]["ItemAdjustmentLineDetail"]["QtyDiff"], -2.0);
+    }
+}
@@ -9,8 +9,8 @@ use super::common::{
 #[cfg(feature = "builder")]
 use crate::error::QBTypeError;
 use crate::{
-    LineField, QBCreatable, QBDeletable, QBFullUpdatable, QBItem, QBPDFable, QBSendable,
-    QBSparseUpdateable, QBVoidable,
+    validation::Validator, LineField, QBCreatable, QBDeletable, QBFullUpdatable, QBPDFable,
+    QBSendable, QBSparseUpdateable, QBVoidable, RecurringInfo, ValidationIssue,
 };
 
 #[skip_serializing_none]
@@ -80,6 +80,8 @@ pub struct Invoice {
     pub print_status: Option<PrintStatus>,
     /// Reference to the sales terms for the invoice
     pub sales_term_ref: Option<NtRef>,
+    /// Reference to the currency for the transaction
+    pub currency_ref: Option<NtRef>,
     /// Exchange rate for the transaction
     pub exchange_rate: Option<f64>,
     /// Deposit amount for the invoice
@@ -100,6 +102,8 @@ pub struct Invoice {
     pub invoice_link: Option<String>,
     /// Reference to recurring template data
     pub recur_data_ref: Option<NtRef>,
+    /// Recurrence settings, set only on the template inside a `RecurringTransaction`
+    pub recurring_info: Option<RecurringInfo>,
     /// Reference to tax exemption information
     pub tax_exemption_ref: Option<NtRef>,
     /// Current balance of the invoice
@@ -128,8 +132,11 @@ pub struct Invoice {
 }
 
 impl QBCreatable for Invoice {
-    fn can_create(&self) -> bool {
-        self.customer_ref.is_some() && self.line.can_create()
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require("CustomerRef", &self.customer_ref)
+            .nested("Line", self.line.validate_create())
+            .finish()
     }
 }
 
@@ -137,8 +144,11 @@ impl QBDeletable for Invoice {}
 impl QBVoidable for Invoice {}
 
 impl QBFullUpdatable for Invoice {
-    fn can_full_update(&self) -> bool {
-        self.has_read() && self.can_create()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
     }
 }
 
@@ -5,7 +5,7 @@ use serde_with::skip_serializing_none;
 use super::common::{MetaData, NtRef};
 #[cfg(feature = "builder")]
 use crate::error::QBTypeError;
-use crate::{QBCreatable, QBFullUpdatable, QBItem};
+use crate::{validation::Validator, QBCreatable, QBFullUpdatable, ValidationIssue, ValidationRule};
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
@@ -20,12 +20,13 @@ use crate::{QBCreatable, QBFullUpdatable, QBItem};
 /// Represents a product or service that can be purchased or sold. Items determine posting accounts (income, expense, inventory) and pricing.
 ///
 /// Creation requirements:
-/// - `QBCreatable::can_create()` returns true when:
-///   - `name` and `expense_account_ref` are present, and
-///   - for `ItemType::Inventory`: `income_account_ref`, `asset_account_ref`, `inv_start_date`, and `qty_on_hand` are present
-///   - for `ItemType::Service`: `income_account_ref` is present
-///   - for `ItemType::NonInventory`: no additional fields beyond `name` and `expense_account_ref`
-///   - if `item_type` is `None`: `asset_account_ref` must be present
+/// - `QBCreatable::can_create()` returns true when `name` is present, and:
+///   - for `ItemType::Inventory`: `expense_account_ref`, `income_account_ref`, `asset_account_ref`, `inv_start_date`, and `qty_on_hand` are present
+///   - for `ItemType::Service`: `expense_account_ref` and `income_account_ref` are present
+///   - for `ItemType::NonInventory`: `expense_account_ref` is present
+///   - for `ItemType::Category`: no account references are set
+///   - for `ItemType::Group`: `item_group_detail` lists at least one component, each with an `ItemRef` and a positive `Qty`
+///   - if `item_type` is `None`: `expense_account_ref` and `asset_account_ref` are present
 ///
 /// Update semantics:
 /// - `QBFullUpdatable::can_full_update()` returns true when `has_read()` is true (ID + sync token) and `name` is present.
@@ -115,41 +116,145 @@ pub struct Item {
     pub taxable: Option<bool>,
     pub track_qty_on_hand: Option<bool>,
     pub unit_price: Option<f64>,
+
+    /// Components of a `Group` (bundle) item.
+    ///
+    /// Required for Group item types.
+    pub item_group_detail: Option<ItemGroupDetail>,
+
+    /// If true, the components of a `Group` item are printed on sales forms instead of the bundle.
+    pub print_grouped_items: Option<bool>,
 }
 
 /// Item Type
 ///
-/// Classification that specifies whether the item is an inventory item, a service item, or a non-inventory item.
+/// Classification that specifies whether the item is an inventory item, a service item, a non-inventory item,
+/// a category used to organize other items, or a group (bundle) of other items.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
 pub enum ItemType {
     Inventory,
     Service,
     #[default]
     NonInventory,
+    Category,
+    Group,
+}
+
+/// `ItemGroupDetail`
+///
+/// Components of a `Group` (bundle) item.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ItemGroupDetail {
+    pub item_group_line: Option<Vec<ItemGroupLine>>,
+}
+
+/// `ItemGroupLine`
+///
+/// One component of a bundle and the quantity of it included in one bundle.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ItemGroupLine {
+    /// Reference to the component item
+    pub item_ref: Option<NtRef>,
+    /// Quantity of the component in one bundle
+    pub qty: Option<f64>,
+}
+
+impl QBCreatable for ItemGroupDetail {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let lines = self.item_group_line.as_deref().unwrap_or_default();
+        let mut v = Validator::new();
+        v.check(
+            !lines.is_empty(),
+            "ItemGroupLine",
+            ValidationRule::Required,
+            "at least one component is required",
+        );
+        for (i, line) in lines.iter().enumerate() {
+            let path = format!("ItemGroupLine[{i}].Qty");
+            v.require(&format!("ItemGroupLine[{i}].ItemRef"), &line.item_ref)
+                .require(&path, &line.qty)
+                .check(
+                    line.qty.is_none_or(|qty| qty > 0.0),
+                    &path,
+                    ValidationRule::InvalidValue,
+                    "Qty must be positive",
+                );
+        }
+        v.finish()
+    }
 }
 
 impl QBCreatable for Item {
-    fn can_create(&self) -> bool {
-        self.name.is_some()
-            && self.expense_account_ref.is_some()
-            && match self.item_type.as_ref() {
-                Some(typ) => match *typ {
-                    ItemType::Inventory => {
-                        self.income_account_ref.is_some()
-                            && self.asset_account_ref.is_some()
-                            && self.inv_start_date.is_some()
-                            && self.qty_on_hand.is_some()
-                    }
-                    ItemType::Service => self.income_account_ref.is_some(),
-                    ItemType::NonInventory => true,
-                },
-                None => self.asset_account_ref.is_some(),
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require("Name", &self.name);
+        match self.item_type.as_ref() {
+            Some(ItemType::Category) => {
+                for (path, account) in [
+                    ("IncomeAccountRef", &self.income_account_ref),
+                    ("ExpenseAccountRef", &self.expense_account_ref),
+                    ("AssetAccountRef", &self.asset_account_ref),
+                ] {
+                    v.check(
+                        account.is_none(),
+                        path,
+                        ValidationRule::InvalidValue,
+                        format!("{path} must not be set when Type is Category"),
+                    );
+                }
+                return v.finish();
+            }
+            Some(ItemType::Group) => {
+                v.require_because(
+                    "ItemGroupDetail",
+                    &self.item_group_detail,
+                    "when Type is Group",
+                );
+                if let Some(detail) = &self.item_group_detail {
+                    v.nested("ItemGroupDetail", detail.validate_create());
+                }
+                return v.finish();
+            }
+            _ => {}
+        }
+        v.require("ExpenseAccountRef", &self.expense_account_ref);
+        match self.item_type.as_ref() {
+            Some(ItemType::Inventory) => {
+                let reason = "when Type is Inventory";
+                v.require_because("IncomeAccountRef", &self.income_account_ref, reason)
+                    .require_because("AssetAccountRef", &self.asset_account_ref, reason)
+                    .require_because("InvStartDate", &self.inv_start_date, reason)
+                    .require_because("QtyOnHand", &self.qty_on_hand, reason);
+            }
+            Some(ItemType::Service) => {
+                v.require_because(
+                    "IncomeAccountRef",
+                    &self.income_account_ref,
+                    "when Type is Service",
+                );
+            }
+            None => {
+                v.require_because(
+                    "AssetAccountRef",
+                    &self.asset_account_ref,
+                    "when Type is not set",
+                );
             }
+            _ => {}
+        }
+        v.finish()
     }
 }
 
 impl QBFullUpdatable for Item {
-    fn can_full_update(&self) -> bool {
-        self.has_read() && self.name.is_some()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .require("Name", &self.name)
+            .finish()
     }
 }
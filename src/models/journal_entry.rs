@@ -0,0 +1,290 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{GlobalTaxCalculation, MetaData, NtRef, TxnTaxDetail};
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
+use crate::{
+    validation::Validator, Account, AccountType, LineDetail, LineField, PostingType, QBCreatable,
+    QBDeletable, QBFullUpdatable, QBSparseUpdateable, RecurringInfo, ValidationIssue,
+    ValidationRule,
+};
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// `JournalEntry`
+///
+/// Represents a general journal entry: a set of debit and credit lines posted directly to accounts.
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires at least one line, and every line needs an `Amount`
+///   and a `JournalEntryLineDetail` with `PostingType` and `AccountRef`.
+/// - Debits and credits must balance.
+///
+/// `QuickBooks` also rejects lines posting to Accounts Receivable or Accounts Payable without an
+/// `Entity`. References do not carry the account type, so `validate_create()` cannot see this;
+/// check it with [`JournalEntry::validate_accounts`] before creating.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/journalentry>
+pub struct JournalEntry {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the transaction. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Document number for the journal entry
+    pub doc_number: Option<String>,
+    /// Date of the transaction in YYYY-MM-DD format
+    pub txn_date: Option<NaiveDate>,
+    /// Private note for the journal entry
+    pub private_note: Option<String>,
+    /// Debit and credit lines of the journal entry
+    pub line: Option<LineField>,
+    /// Indicates the entry is an adjusting entry
+    pub adjustment: Option<bool>,
+    /// Total amount of the journal entry
+    pub total_amt: Option<f64>,
+    /// Total amount in home currency
+    pub home_total_amt: Option<f64>,
+    /// Reference to the currency for the transaction
+    pub currency_ref: Option<NtRef>,
+    /// Exchange rate for the transaction
+    pub exchange_rate: Option<f64>,
+    /// Tax details for the transaction
+    pub txn_tax_detail: Option<TxnTaxDetail>,
+    /// Method in which tax is applied
+    pub global_tax_calculation: Option<GlobalTaxCalculation>,
+    /// Reference to recurring template data
+    pub recur_data_ref: Option<NtRef>,
+    /// Recurrence settings, set only on the template inside a `RecurringTransaction`
+    pub recurring_info: Option<RecurringInfo>,
+}
+
+impl JournalEntry {
+    /// Sum of the amounts on `Debit` lines.
+    #[must_use]
+    pub fn debit_total(&self) -> f64 {
+        self.posting_total(&PostingType::Debit)
+    }
+
+    /// Sum of the amounts on `Credit` lines.
+    #[must_use]
+    pub fn credit_total(&self) -> f64 {
+        self.posting_total(&PostingType::Credit)
+    }
+
+    /// Returns true if debits and credits are equal to the cent.
+    #[must_use]
+    pub fn is_balanced(&self) -> bool {
+        (self.debit_total() - self.credit_total()).abs() < 0.005
+    }
+
+    /// Checks that every line posting to an Accounts Receivable or Accounts Payable account among
+    /// `accounts` names an `Entity`.
+    ///
+    /// Lines whose account is not in `accounts` are skipped, so pass every account the entry
+    /// posts to.
+    pub fn validate_accounts(&self, accounts: &[Account]) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        for (i, line) in self.line.iter().flatten().enumerate() {
+            let LineDetail::JournalEntryLineDetail(detail) = &line.line_detail else {
+                continue;
+            };
+            let id = detail.account_ref.as_ref().and_then(|r| r.value.as_ref());
+            let is_ar_ap = accounts
+                .iter()
+                .find(|account| id.is_some() && account.id.as_ref() == id)
+                .and_then(|account| account.account_type.as_ref())
+                .is_some_and(AccountType::is_ar_ap);
+            if is_ar_ap {
+                v.require_because(
+                    &format!("Line[{i}].JournalEntryLineDetail.Entity"),
+                    &detail.entity,
+                    "when AccountRef is an Accounts Receivable or Accounts Payable account",
+                );
+            }
+        }
+        v.finish()
+    }
+
+    fn posting_total(&self, posting_type: &PostingType) -> f64 {
+        self.line
+            .iter()
+            .flatten()
+            .filter_map(|line| match &line.line_detail {
+                LineDetail::JournalEntryLineDetail(detail)
+                    if detail.posting_type.as_ref() == Some(posting_type) =>
+                {
+                    line.amount
+                }
+                _ => None,
+            })
+            .sum()
+    }
+}
+
+impl QBCreatable for JournalEntry {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.nested("Line", self.line.validate_create()).check(
+            self.line.as_ref().is_none_or(|lines| !lines.is_empty()),
+            "Line",
+            ValidationRule::Required,
+            "at least one line is required",
+        );
+        for (i, line) in self.line.iter().flatten().enumerate() {
+            let path = format!("Line[{i}]");
+            let LineDetail::JournalEntryLineDetail(detail) = &line.line_detail else {
+                v.check(
+                    false,
+                    &format!("{path}.DetailType"),
+                    ValidationRule::InvalidValue,
+                    "journal entry lines must use JournalEntryLineDetail",
+                );
+                continue;
+            };
+            v.require(
+                &format!("{path}.JournalEntryLineDetail.PostingType"),
+                &detail.posting_type,
+            )
+            .require(
+                &format!("{path}.JournalEntryLineDetail.AccountRef"),
+                &detail.account_ref,
+            );
+        }
+        v.check(
+            self.is_balanced(),
+            "Line",
+            ValidationRule::InvalidValue,
+            format!(
+                "debits ({:.2}) and credits ({:.2}) must balance",
+                self.debit_total(),
+                self.credit_total()
+            ),
+        );
+        v.finish()
+    }
+}
+
+impl QBDeletable for JournalEntry {}
+
+impl QBFullUpdatable for JournalEntry {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+impl QBSparseUpdateable for JournalEntry {
+    fn can_sparse_update(&self) -> bool {
+        self.can_full_update()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JournalEntityType, JournalEntryEntity, JournalEntryLineDetail, Line};
+
+    fn line(posting_type: PostingType, account: (&str, &str), amount: f64) -> Line {
+        Line {
+            amount: Some(amount),
+            line_detail: LineDetail::JournalEntryLineDetail(JournalEntryLineDetail {
+                posting_type: Some(posting_type),
+                account_ref: Some(account.into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn journal_entry_must_balance_and_name_ar_ap_entities() {
+        let mut entry = JournalEntry {
+            line: Some(vec![
+                line(PostingType::Debit, ("Customer Balances", "84"), 100.0),
+                line(PostingType::Credit, ("Services", "1"), 90.0),
+            ]),
+            ..Default::default()
+        };
+        let issues = entry.validate_create().unwrap_err();
+        let paths: Vec<_> = issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, ["Line"]);
+        assert_eq!(
+            issues[0].message,
+            "debits (100.00) and credits (90.00) must balance"
+        );
+
+        // A renamed A/R account is recognised by its type, not its name
+        let accounts = [Account {
+            id: Some("84".into()),
+            name: Some("Customer Balances".into()),
+            account_type: Some(AccountType::AccountsReceivable),
+            ..Default::default()
+        }];
+        let issues = entry.validate_accounts(&accounts).unwrap_err();
+        assert_eq!(issues[0].path, "Line[0].JournalEntryLineDetail.Entity");
+        assert!(entry.validate_accounts(&[]).is_ok());
+
+        let lines = entry.line.as_mut().unwrap();
+        lines[1].amount = Some(100.0);
+        if let LineDetail::JournalEntryLineDetail(detail) = &mut lines[0].line_detail {
+            detail.entity = Some(JournalEntryEntity {
+                entity_type: Some(JournalEntityType::Customer),
+                entity_ref: Some(("Acme", "7").into()),
+            });
+        }
+        assert!(entry.can_create());
+        assert!(entry.validate_accounts(&accounts).is_ok());
+
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["Line"][0]["DetailType"], "JournalEntryLineDetail");
+        assert_eq!(
+            json["Line"][0]["JournalEntryLineDetail"]["Entity"]["Type"],
+            "Customer"
+        );
+        let round_trip: JournalEntry = serde_json::from_value(json).unwrap();
+        assert_eq!(round_trip, entry);
+
+        let empty = JournalEntry {
+            line: Some(vec![]),
+            ..Default::default()
+        };
+        assert_eq!(empty.validate_create().unwrap_err()[0].path, "Line");
+        assert_eq!(
+            JournalEntry::default().validate_create().unwrap_err().len(),
+            1
+        );
+        let untyped = JournalEntry {
+            line: Some(vec![Line {
+                amount: Some(5.0),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        assert_eq!(
+            untyped.validate_create().unwrap_err()[0].path,
+            "Line[0].DetailType"
+        );
+    }
+}
@@ -3,7 +3,8 @@ use serde::{ser::SerializeStruct, Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use super::common::{LinkedTxn, NtRef};
-use crate::QBCreatable;
+use crate::validation::Validator;
+use crate::{QBCreatable, ValidationIssue, ValidationRule};
 
 /// `LineField`
 ///
@@ -26,8 +27,9 @@ use crate::error::QBTypeError;
 /// Represents a single line within a transaction (e.g., Invoice, Bill, `SalesReceipt`). Encapsulates amount, description, and a specific `LineDetail` subtype.
 /// Note: This type has no standalone `QuickBooks` API endpoint and is only used as a nested component.
 pub struct Line {
-    /// Details of the line item
-    #[serde(flatten)]
+    /// Details of the line item; `LineDetail::None` for lines that only carry an amount and
+    /// linked transactions (e.g. payment lines)
+    #[serde(flatten, deserialize_with = "line_detail_or_none")]
     pub line_detail: LineDetail,
     /// Amount total for the line item
     pub amount: Option<f64>,
@@ -40,24 +42,34 @@ pub struct Line {
 }
 
 impl QBCreatable for Line {
-    fn can_create(&self) -> bool {
-        self.amount.is_some()
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new().require("Amount", &self.amount).finish()
     }
 }
 
 impl QBCreatable for Option<LineField> {
-    fn can_create(&self) -> bool {
-        if let Some(data) = self {
-            data.can_create()
-        } else {
-            false
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        match self {
+            Some(data) => data.validate_create(),
+            None => Validator::new()
+                .check(
+                    false,
+                    "",
+                    ValidationRule::Required,
+                    "at least one line is required",
+                )
+                .finish(),
         }
     }
 }
 
 impl QBCreatable for LineField {
-    fn can_create(&self) -> bool {
-        self.iter().all(QBCreatable::can_create)
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        for (i, line) in self.iter().enumerate() {
+            v.nested(&format!("[{i}]"), line.validate_create());
+        }
+        v.finish()
     }
 }
 
@@ -102,7 +114,19 @@ impl Serialize for LineDetail {
                 state.serialize_field("TaxLineDetail", data)?;
                 "TaxLineDetail"
             }
-            LineDetail::None => panic!("Cannot serialize Line Detail of None!"),
+            LineDetail::JournalEntryLineDetail(data) => {
+                state.serialize_field("JournalEntryLineDetail", data)?;
+                "JournalEntryLineDetail"
+            }
+            LineDetail::DepositLineDetail(data) => {
+                state.serialize_field("DepositLineDetail", data)?;
+                "DepositLineDetail"
+            }
+            LineDetail::ItemAdjustmentLineDetail(data) => {
+                state.serialize_field("ItemAdjustmentLineDetail", data)?;
+                "ItemAdjustmentLineDetail"
+            }
+            LineDetail::None => return state.end(),
         };
 
         state.serialize_field("DetailType", detail_type)?;
@@ -110,6 +134,49 @@ impl Serialize for LineDetail {
     }
 }
 
+/// Names of the `LineDetail` variants, as used for `DetailType` and the detail key.
+const DETAIL_TYPES: &[&str] = &[
+    "SalesItemLineDetail",
+    "GroupLineDetail",
+    "DescriptionLineDetail",
+    "DiscountLineDetail",
+    "SubTotalLineDetail",
+    "ItemBasedExpenseLineDetail",
+    "AccountBasedExpenseLineDetail",
+    "TaxLineDetail",
+    "JournalEntryLineDetail",
+    "DepositLineDetail",
+    "ItemAdjustmentLineDetail",
+];
+
+/// `LineDetail::None` only when the line has neither a `DetailType` nor a detail key; a detail
+/// that is present but fails to decode is an error.
+fn line_detail_or_none<'de, D>(deserializer: D) -> Result<LineDetail, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    use serde_json::{Map, Value};
+
+    let mut fields = Map::<String, Value>::deserialize(deserializer)?;
+    let detail_type = match fields.remove("DetailType") {
+        Some(Value::String(detail_type)) => Some(detail_type),
+        Some(other) => return Err(D::Error::custom(format!("invalid DetailType: {other}"))),
+        None => DETAIL_TYPES
+            .iter()
+            .find(|name| fields.contains_key(**name))
+            .map(|name| (*name).to_owned()),
+    };
+    let Some(detail_type) = detail_type else {
+        return Ok(LineDetail::None);
+    };
+    let detail = fields
+        .remove(&detail_type)
+        .unwrap_or_else(|| Value::Object(Map::new()));
+    LineDetail::deserialize(Value::Object(Map::from_iter([(detail_type, detail)])))
+        .map_err(D::Error::custom)
+}
+
 impl std::fmt::Display for Line {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -133,6 +200,9 @@ pub enum LineDetail {
     ItemBasedExpenseLineDetail(ItemBasedExpenseLineDetail),
     AccountBasedExpenseLineDetail(AccountBasedExpenseLineDetail),
     TaxLineDetail(TaxLineDetail),
+    JournalEntryLineDetail(JournalEntryLineDetail),
+    DepositLineDetail(DepositLineDetail),
+    ItemAdjustmentLineDetail(ItemAdjustmentLineDetail),
     #[default]
     None,
 }
@@ -303,6 +373,107 @@ pub struct TaxLineDetail {
     pub tax_percent: Option<f64>,
 }
 
+/// `PostingType`
+///
+/// Side of the ledger a journal entry line posts to.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub enum PostingType {
+    #[default]
+    Debit,
+    Credit,
+}
+
+/// `JournalEntityType`
+///
+/// Kind of name a journal entry line is associated with.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub enum JournalEntityType {
+    #[default]
+    Customer,
+    Vendor,
+    Employee,
+}
+
+/// `JournalEntryEntity`
+///
+/// Customer, vendor or employee a journal entry line is posted against.
+/// Required on lines that post to Accounts Receivable or Accounts Payable.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct JournalEntryEntity {
+    #[serde(rename = "Type")]
+    pub entity_type: Option<JournalEntityType>,
+    pub entity_ref: Option<NtRef>,
+}
+
+/// `JournalEntryLineDetail`
+///
+/// Description of the journal entry line detail
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+pub struct JournalEntryLineDetail {
+    pub posting_type: Option<PostingType>,
+    pub account_ref: Option<NtRef>,
+    pub entity: Option<JournalEntryEntity>,
+    pub class_ref: Option<NtRef>,
+    pub department_ref: Option<NtRef>,
+    pub tax_code_ref: Option<NtRef>,
+    pub tax_applicable_on: Option<String>,
+    pub tax_amount: Option<f64>,
+    pub billable_status: Option<BillableStatus>,
+}
+
+/// `DepositLineDetail`
+///
+/// Description of the deposit line detail, for funds deposited directly rather than from
+/// Undeposited Funds
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+pub struct DepositLineDetail {
+    /// Customer, vendor or employee the funds were received from
+    pub entity: Option<NtRef>,
+    /// Account the funds are received into (e.g. an income account)
+    pub account_ref: Option<NtRef>,
+    pub class_ref: Option<NtRef>,
+    pub payment_method_ref: Option<NtRef>,
+    pub check_num: Option<String>,
+    pub tax_code_ref: Option<NtRef>,
+    pub tax_applicable_on: Option<String>,
+}
+
+/// `ItemAdjustmentLineDetail`
+///
+/// Description of the inventory adjustment line detail, changing the quantity on hand of an
+/// inventory item
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+pub struct ItemAdjustmentLineDetail {
+    /// Reference to the inventory item being adjusted
+    pub item_ref: Option<NtRef>,
+    /// Change in quantity on hand; negative to remove stock
+    pub qty_diff: Option<f64>,
+    pub class_ref: Option<NtRef>,
+}
+
 #[test]
 fn deserialize_line() {
     let test: LineField = serde_json::from_str(
@@ -370,3 +541,24 @@ fn deserialize_line() {
     .unwrap();
     dbg!(test);
 }
+
+#[test]
+fn deserialize_line_detail_errors() {
+    let bad = serde_json::from_str::<Line>(
+        r#"{"Amount": 10.0, "DetailType": "SalesItemLineDetail", "SalesItemLineDetail": {"Qty": "not a number"}}"#,
+    );
+    assert!(bad.is_err());
+
+    let payment: Line = serde_json::from_str(
+        r#"{"Amount": 10.0, "LinkedTxn": [{"TxnId": "130", "TxnType": "Invoice"}]}"#,
+    )
+    .unwrap();
+    assert_eq!(payment.line_detail, LineDetail::None);
+
+    let subtotal: Line =
+        serde_json::from_str(r#"{"Amount": 5.0, "DetailType": "SubTotalLineDetail"}"#).unwrap();
+    assert!(matches!(
+        subtotal.line_detail,
+        LineDetail::SubTotalLineDetail(_)
+    ));
+}
@@ -2,37 +2,83 @@ mod account;
 mod attachable;
 mod bill;
 mod bill_payment;
+mod budget;
+mod capability;
+mod class;
 pub mod common;
+mod company_currency;
 mod company_info;
+mod credit_memo;
 mod customer;
+mod department;
+mod deposit;
 mod employee;
+mod entitlements;
 mod estimate;
+mod exchange_rate;
+mod hierarchy;
+mod home_currency;
+mod inventory_adjustment;
 mod invoice;
 mod item;
+mod journal_entry;
 mod line;
 mod payment;
+mod payment_method;
 mod preferences;
+mod purchase;
+mod purchase_order;
+mod recurring_transaction;
+mod refund_receipt;
 mod sales_receipt;
+mod tax_agency;
 mod tax_code;
 mod tax_rate;
+mod tax_service;
 mod term;
+mod time_activity;
+mod transfer;
 mod vendor;
+mod vendor_credit;
 
 pub use account::*;
 pub use attachable::*;
 pub use bill::*;
 pub use bill_payment::*;
+pub use budget::*;
+pub use capability::*;
+pub use class::*;
+pub use company_currency::*;
 pub use company_info::*;
+pub use credit_memo::*;
 pub use customer::*;
+pub use department::*;
+pub use deposit::*;
 pub use employee::*;
+pub use entitlements::*;
 pub use estimate::*;
+pub use exchange_rate::*;
+pub use hierarchy::*;
+pub use home_currency::*;
+pub use inventory_adjustment::*;
 pub use invoice::*;
 pub use item::*;
+pub use journal_entry::*;
 pub use line::*;
 pub use payment::*;
+pub use payment_method::*;
 pub use preferences::*;
+pub use purchase::*;
+pub use purchase_order::*;
+pub use recurring_transaction::*;
+pub use refund_receipt::*;
 pub use sales_receipt::*;
+pub use tax_agency::*;
 pub use tax_code::*;
 pub use tax_rate::*;
+pub use tax_service::*;
 pub use term::*;
+pub use time_activity::*;
+pub use transfer::*;
 pub use vendor::*;
+pub use vendor_credit::*;
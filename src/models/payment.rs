@@ -3,10 +3,10 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use super::common::{CreditCardPayment, MetaData, NtRef};
-#[cfg(feature = "builder")]
 use crate::error::QBTypeError;
 use crate::{
-    LineField, QBCreatable, QBDeletable, QBFullUpdatable, QBItem, QBPDFable, QBSendable, QBVoidable,
+    request::QBRequest, validation::Validator, LineField, QBCreatable, QBDeletable,
+    QBFullUpdatable, QBPDFable, QBSendable, QBVoidable, ValidationIssue,
 };
 
 #[skip_serializing_none]
@@ -75,16 +75,26 @@ pub struct Payment {
 }
 
 impl QBCreatable for Payment {
-    fn can_create(&self) -> bool {
-        self.total_amt.is_some() && self.customer_ref.is_some()
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require("TotalAmt", &self.total_amt)
+            .require("CustomerRef", &self.customer_ref)
+            .finish()
     }
 }
 
 impl QBDeletable for Payment {}
-impl QBVoidable for Payment {}
+impl QBVoidable for Payment {
+    fn void_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError> {
+        QBRequest::void_via_update(realm_id, self)
+    }
+}
 impl QBFullUpdatable for Payment {
-    fn can_full_update(&self) -> bool {
-        self.has_read() && self.can_create()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
     }
 }
 
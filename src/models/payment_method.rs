@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::MetaData;
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
+use crate::{
+    validation::Validator, QBCreatable, QBFullUpdatable, QBSparseUpdateable, ValidationIssue,
+};
+
+/// `PaymentMethodType` Enum
+///
+/// Whether a payment method is a credit card
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentMethodType {
+    CreditCard,
+    #[default]
+    NonCreditCard,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// `PaymentMethod`
+///
+/// Represents a method of payment (e.g. cash, check, a card brand) referenced by payments and
+/// sales receipts through `PaymentMethodRef`. Payment methods cannot be deleted, only made inactive.
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires `name`.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/paymentmethod>
+pub struct PaymentMethod {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the entity. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Name of the payment method
+    pub name: Option<String>,
+    /// Whether the payment method is a credit card
+    #[serde(rename = "Type")]
+    pub method_type: Option<PaymentMethodType>,
+    /// Indicates if the payment method is active
+    pub active: Option<bool>,
+}
+
+impl QBCreatable for PaymentMethod {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new().require("Name", &self.name).finish()
+    }
+}
+
+impl QBFullUpdatable for PaymentMethod {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+impl QBSparseUpdateable for PaymentMethod {
+    fn can_sparse_update(&self) -> bool {
+        self.can_full_update()
+    }
+}
@@ -6,7 +6,8 @@ use serde_with::skip_serializing_none;
 use crate::error::QBTypeError;
 use crate::{
     common::{Email, MetaData, NtRef},
-    QBFullUpdatable, QBItem,
+    validation::Validator,
+    QBFullUpdatable, ValidationIssue,
 };
 
 #[skip_serializing_none]
@@ -247,8 +248,21 @@ pub struct CurrencyPrefs {
     pub multi_currency_enabled: Option<bool>,
 }
 
+impl Preferences {
+    /// Currency code of the company's home currency, e.g. `"USD"`.
+    #[must_use]
+    pub fn home_currency_code(&self) -> Option<&str> {
+        self.currency_prefs
+            .as_ref()?
+            .home_currency
+            .as_ref()?
+            .value
+            .as_deref()
+    }
+}
+
 impl QBFullUpdatable for Preferences {
-    fn can_full_update(&self) -> bool {
-        self.has_read()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new().require_read(self).finish()
     }
 }
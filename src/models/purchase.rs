@@ -0,0 +1,226 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{
+    Addr, CustomField, GlobalTaxCalculation, LinkedTxn, MetaData, NtRef, PrintStatus, TxnTaxDetail,
+};
+use crate::error::QBTypeError;
+use crate::{
+    request::QBRequest, validation::Validator, LineDetail, LineField, QBCreatable, QBDeletable,
+    QBFullUpdatable, QBVoidable, RecurringInfo, ValidationIssue, ValidationRule,
+};
+
+/// `PaymentType` Enum
+///
+/// How a purchase was paid for
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, Default)]
+pub enum PaymentType {
+    #[default]
+    Cash,
+    Check,
+    CreditCard,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// Purchase
+///
+/// Represents an expense paid immediately by cash, check or credit card, as opposed to a `Bill` paid later.
+/// Line items use `AccountBasedExpenseLineDetail` or `ItemBasedExpenseLineDetail`.
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires `account_ref`, `payment_type` and at least one valid expense line.
+/// - A `Check` purchase also needs the payee in `entity_ref`.
+/// - `credit` is only allowed on `CreditCard` purchases and `print_status` only on `Check` purchases.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+/// - Voiding is a sparse update with `include=void`.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/purchase>
+pub struct Purchase {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the transaction. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Bank or credit card account the purchase is paid from
+    pub account_ref: Option<NtRef>,
+    /// How the purchase was paid
+    pub payment_type: Option<PaymentType>,
+    /// Payee of the purchase (vendor, customer or employee)
+    pub entity_ref: Option<NtRef>,
+    /// Marks a credit card purchase as a refund (credit card credit)
+    pub credit: Option<bool>,
+    /// Line items for the purchase
+    pub line: Option<LineField>,
+    /// Total amount of the purchase
+    pub total_amt: Option<f64>,
+    /// Print status of a check purchase
+    pub print_status: Option<PrintStatus>,
+    /// Document number (check number) for the purchase
+    pub doc_number: Option<String>,
+    /// Date of the transaction in YYYY-MM-DD format
+    pub txn_date: Option<NaiveDate>,
+    /// Private note for the transaction
+    pub private_note: Option<String>,
+    /// Address the payment is sent to
+    pub remit_to_addr: Option<Addr>,
+    /// Reference to the payment method
+    pub payment_method_ref: Option<NtRef>,
+    /// Reference to the department for the transaction
+    pub department_ref: Option<NtRef>,
+    /// Reference to the currency for the transaction
+    pub currency_ref: Option<NtRef>,
+    /// Exchange rate for the transaction
+    pub exchange_rate: Option<f64>,
+    /// Tax details for the transaction
+    pub txn_tax_detail: Option<TxnTaxDetail>,
+    /// Method in which tax is applied
+    pub global_tax_calculation: Option<GlobalTaxCalculation>,
+    /// Source of the transaction
+    pub txn_source: Option<String>,
+    /// Linked transactions
+    pub linked_txn: Option<Vec<LinkedTxn>>,
+    /// Reference to recurring template data
+    pub recur_data_ref: Option<NtRef>,
+    /// Recurrence settings, set only on the template inside a `RecurringTransaction`
+    pub recurring_info: Option<RecurringInfo>,
+    /// Custom fields for the entity
+    pub custom_field: Option<Vec<CustomField>>,
+}
+
+impl QBCreatable for Purchase {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require("AccountRef", &self.account_ref)
+            .require("PaymentType", &self.payment_type)
+            .nested("Line", self.line.validate_create())
+            .check(
+                self.line.as_ref().is_none_or(|lines| !lines.is_empty()),
+                "Line",
+                ValidationRule::Required,
+                "at least one line is required",
+            );
+        for (i, line) in self.line.iter().flatten().enumerate() {
+            v.check(
+                matches!(
+                    line.line_detail,
+                    LineDetail::AccountBasedExpenseLineDetail(_)
+                        | LineDetail::ItemBasedExpenseLineDetail(_)
+                ),
+                &format!("Line[{i}].DetailType"),
+                ValidationRule::InvalidValue,
+                "purchase lines must use AccountBasedExpenseLineDetail or ItemBasedExpenseLineDetail",
+            );
+        }
+        let payment_type = self.payment_type.as_ref();
+        if payment_type == Some(&PaymentType::Check) {
+            v.require_because("EntityRef", &self.entity_ref, "when PaymentType is Check");
+        }
+        v.check(
+            self.credit != Some(true) || payment_type == Some(&PaymentType::CreditCard),
+            "Credit",
+            ValidationRule::InvalidValue,
+            "Credit is only allowed when PaymentType is CreditCard",
+        )
+        .check(
+            matches!(self.print_status, None | Some(PrintStatus::NotSet))
+                || payment_type == Some(&PaymentType::Check),
+            "PrintStatus",
+            ValidationRule::InvalidValue,
+            "PrintStatus is only allowed when PaymentType is Check",
+        );
+        v.finish()
+    }
+}
+
+impl QBDeletable for Purchase {}
+impl QBVoidable for Purchase {
+    fn void_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError> {
+        QBRequest::void_via_update(realm_id, self)
+    }
+}
+impl QBFullUpdatable for Purchase {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AccountBasedExpenseLineDetail, Line, QBVoidable};
+
+    fn expense_line() -> Line {
+        Line {
+            amount: Some(42.0),
+            line_detail: LineDetail::AccountBasedExpenseLineDetail(AccountBasedExpenseLineDetail {
+                account_ref: ("Meals", "13").into(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn creation_rules_depend_on_payment_type() {
+        let mut purchase = Purchase {
+            account_ref: Some(("Checking", "35").into()),
+            payment_type: Some(PaymentType::Check),
+            print_status: Some(PrintStatus::NeedToPrint),
+            line: Some(vec![expense_line()]),
+            ..Default::default()
+        };
+        let issues = purchase.validate_create().unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "EntityRef");
+        assert_eq!(issues[0].rule, ValidationRule::ConditionallyRequired);
+
+        purchase.payment_type = Some(PaymentType::CreditCard);
+        purchase.credit = Some(true);
+        let issues = purchase.validate_create().unwrap_err();
+        let paths: Vec<_> = issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, ["PrintStatus"]);
+
+        purchase.print_status = None;
+        assert!(purchase.can_create());
+
+        purchase.line = Some(vec![Line {
+            amount: Some(42.0),
+            ..Default::default()
+        }]);
+        assert_eq!(
+            purchase.validate_create().unwrap_err()[0].path,
+            "Line[0].DetailType"
+        );
+        purchase.line = Some(vec![]);
+        assert_eq!(purchase.validate_create().unwrap_err()[0].path, "Line");
+        purchase.line = Some(vec![expense_line()]);
+
+        purchase.id = Some("252".into());
+        purchase.sync_token = Some("0".into());
+        assert_eq!(
+            purchase.void_request("1234").unwrap().to_string(),
+            "POST /v3/company/1234/purchase?operation=update&include=void&minorversion=75"
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{
+    Addr, CustomField, Email, EmailStatus, GlobalTaxCalculation, LinkedTxn, MetaData, NtRef,
+    TxnTaxDetail,
+};
+use crate::error::QBTypeError;
+use crate::{
+    request, validation::Validator, Bill, LineDetail, LineField, QBCreatable, QBDeletable,
+    QBFullUpdatable, QBItem, QBPDFable, QBSendable, QBSparseUpdateable, ValidationIssue,
+    ValidationRule,
+};
+
+/// `POStatus` Enum
+///
+/// Whether a purchase order still has lines to receive or bill
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, Default)]
+pub enum POStatus {
+    #[default]
+    Open,
+    Closed,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// `PurchaseOrder`
+///
+/// Represents a request to buy goods or services from a vendor. A purchase order is non-posting;
+/// it is fulfilled by a `Bill` (see [`PurchaseOrder::to_bill`]) linked back to it.
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires `vendor_ref`, `ap_account_ref` and at least one valid
+///   item or account expense line.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/purchaseorder>
+pub struct PurchaseOrder {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the transaction. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Reference to the vendor the order is placed with
+    pub vendor_ref: Option<NtRef>,
+    /// Reference to the Accounts Payable account for the order
+    #[serde(rename = "APAccountRef")]
+    pub ap_account_ref: Option<NtRef>,
+    /// Item and account expense lines for the order
+    pub line: Option<LineField>,
+    /// Whether the order is open or closed
+    #[serde(rename = "POStatus")]
+    pub po_status: Option<POStatus>,
+    /// Email address the order is sent to
+    #[serde(rename = "POEmail")]
+    pub po_email: Option<Email>,
+    /// Email status of the order
+    pub email_status: Option<EmailStatus>,
+    /// Address of the vendor
+    pub vendor_addr: Option<Addr>,
+    /// Address the goods are shipped to
+    pub ship_addr: Option<Addr>,
+    /// Customer the goods are shipped to (drop shipment)
+    pub ship_to: Option<NtRef>,
+    /// Reference to the shipping method
+    pub ship_method_ref: Option<NtRef>,
+    /// Reference to the class for the transaction
+    pub class_ref: Option<NtRef>,
+    /// Reference to the sales terms for the order
+    pub sales_term_ref: Option<NtRef>,
+    /// Date the goods are due
+    pub due_date: Option<NaiveDate>,
+    /// Date of the transaction in YYYY-MM-DD format
+    pub txn_date: Option<NaiveDate>,
+    /// Document number for the order
+    pub doc_number: Option<String>,
+    /// Private note for the transaction
+    pub private_note: Option<String>,
+    /// Message to the vendor, printed on the order
+    pub memo: Option<String>,
+    /// Total amount of the order
+    pub total_amt: Option<f64>,
+    /// Reference to the currency for the transaction
+    pub currency_ref: Option<NtRef>,
+    /// Exchange rate for the transaction
+    pub exchange_rate: Option<f64>,
+    /// Tax details for the transaction
+    pub txn_tax_detail: Option<TxnTaxDetail>,
+    /// Method in which tax is applied
+    pub global_tax_calculation: Option<GlobalTaxCalculation>,
+    /// Linked transactions (e.g. bills that received the order)
+    pub linked_txn: Option<Vec<LinkedTxn>>,
+    /// Reference to recurring template data
+    pub recur_data_ref: Option<NtRef>,
+    /// Custom fields for the entity
+    pub custom_field: Option<Vec<CustomField>>,
+}
+
+impl PurchaseOrder {
+    /// Builds a `Bill` for the open lines of this order.
+    ///
+    /// Expense lines not already linked to a bill are copied, each with a `LinkedTxn` pointing at
+    /// its order line, and the bill itself is linked to the order (`TxnType = "PurchaseOrder"`).
+    /// Vendor, A/P account, terms and currency are carried over; dates and doc number are left
+    /// for the caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QBTypeError::PreconditionFailed` if the order has not been read from `QuickBooks`
+    /// or is closed, and `QBTypeError::MissingField` if it has no vendor or open lines.
+    pub fn to_bill(&self) -> Result<Bill, QBTypeError> {
+        request::precondition::<Self>(
+            self.has_read() && self.po_status != Some(POStatus::Closed),
+            "conversion to Bill",
+        )?;
+        let vendor_ref = self
+            .vendor_ref
+            .clone()
+            .ok_or(QBTypeError::MissingField("vendor_ref"))?;
+        let order_link = LinkedTxn::from_entity(self).ok_or(QBTypeError::MissingField("id"))?;
+
+        let line: LineField = self
+            .line
+            .iter()
+            .flatten()
+            .filter(|line| {
+                matches!(
+                    line.line_detail,
+                    LineDetail::ItemBasedExpenseLineDetail(_)
+                        | LineDetail::AccountBasedExpenseLineDetail(_)
+                ) && !line
+                    .linked_txn
+                    .iter()
+                    .flatten()
+                    .any(|txn| txn.txn_type.as_deref() == Some(Bill::name()))
+            })
+            .map(|line| {
+                let mut line = line.clone();
+                line.linked_txn = Some(vec![order_link.clone().with_line_id(line.id.take())]);
+                line
+            })
+            .collect();
+        if line.is_empty() {
+            return Err(QBTypeError::MissingField("line"));
+        }
+
+        Ok(Bill {
+            vendor_ref: Some(vendor_ref),
+            ap_account_ref: self.ap_account_ref.clone(),
+            sales_term_ref: self.sales_term_ref.clone(),
+            currency_ref: self.currency_ref.clone(),
+            exchange_rate: self.exchange_rate,
+            total_amt: Some(line.iter().filter_map(|line| line.amount).sum()),
+            linked_txn: Some(vec![order_link]),
+            line: Some(line),
+            ..Default::default()
+        })
+    }
+}
+
+impl QBCreatable for PurchaseOrder {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require("VendorRef", &self.vendor_ref)
+            .require("APAccountRef", &self.ap_account_ref)
+            .nested("Line", self.line.validate_create())
+            .check(
+                self.line.as_ref().is_none_or(|lines| !lines.is_empty()),
+                "Line",
+                ValidationRule::Required,
+                "at least one line is required",
+            );
+        for (i, line) in self.line.iter().flatten().enumerate() {
+            v.check(
+                matches!(
+                    line.line_detail,
+                    LineDetail::AccountBasedExpenseLineDetail(_)
+                        | LineDetail::ItemBasedExpenseLineDetail(_)
+                ),
+                &format!("Line[{i}].DetailType"),
+                ValidationRule::InvalidValue,
+                "purchase order lines must use ItemBasedExpenseLineDetail or AccountBasedExpenseLineDetail",
+            );
+        }
+        v.finish()
+    }
+}
+
+impl QBDeletable for PurchaseOrder {}
+
+impl QBFullUpdatable for PurchaseOrder {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+impl QBSparseUpdateable for PurchaseOrder {
+    fn can_sparse_update(&self) -> bool {
+        self.can_full_update()
+    }
+}
+
+impl QBSendable for PurchaseOrder {}
+impl QBPDFable for PurchaseOrder {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ItemBasedExpenseLineDetail, Line};
+
+    fn item_line(id: &str, amount: f64) -> Line {
+        Line {
+            id: Some(id.into()),
+            amount: Some(amount),
+            line_detail: LineDetail::ItemBasedExpenseLineDetail(ItemBasedExpenseLineDetail {
+                item_ref: ("Pump", "11").into(),
+                qty: 2.0,
+                unit_price: amount / 2.0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn to_bill_copies_open_lines_and_links_the_order() {
+        let mut billed = item_line("2", 30.0);
+        billed.linked_txn = Some(vec![LinkedTxn {
+            txn_id: Some("900".into()),
+            txn_type: Some("Bill".into()),
+            txn_line_id: Some("1".into()),
+        }]);
+        let mut order = PurchaseOrder {
+            id: Some("77".into()),
+            sync_token: Some("1".into()),
+            vendor_ref: Some(("Hicks Hardware", "41").into()),
+            ap_account_ref: Some(("Accounts Payable (A/P)", "33").into()),
+            po_status: Some(POStatus::Open),
+            line: Some(vec![item_line("1", 20.0), billed]),
+            ..Default::default()
+        };
+
+        let bill = order.to_bill().unwrap();
+        let link = |line_id: Option<&str>| LinkedTxn {
+            txn_id: Some("77".into()),
+            txn_type: Some("PurchaseOrder".into()),
+            txn_line_id: line_id.map(Into::into),
+        };
+        assert_eq!(bill.linked_txn, Some(vec![link(None)]));
+        let lines = bill.line.as_ref().unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].id, None);
+        assert_eq!(lines[0].linked_txn, Some(vec![link(Some("1"))]));
+        assert_eq!(bill.total_amt, Some(20.0));
+        assert!(bill.can_create());
+
+        assert!(order.can_create());
+        let empty = PurchaseOrder {
+            line: Some(vec![]),
+            ..order.clone()
+        };
+        assert_eq!(empty.validate_create().unwrap_err()[0].path, "Line");
+
+        order.po_status = Some(POStatus::Closed);
+        assert!(matches!(
+            order.to_bill(),
+            Err(QBTypeError::PreconditionFailed { .. })
+        ));
+    }
+}
@@ -0,0 +1,579 @@
+use std::fmt::Display;
+
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_with::skip_serializing_none;
+
+use super::common::MetaData;
+use crate::{
+    request::{self, QBRequest},
+    validation::Validator,
+    Bill, Estimate, Invoice, JournalEntry, Purchase, QBCreatable, QBDeletable, QBFullUpdatable,
+    QBItem, QBTypeError, SalesReceipt, ValidationIssue, ValidationRule,
+};
+
+/// `RecurType` Enum
+///
+/// How `QuickBooks` acts on each occurrence of a recurring transaction
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, Default)]
+pub enum RecurType {
+    /// Transactions are created on schedule
+    #[default]
+    Automated,
+    /// The user is reminded to create each transaction
+    Reminded,
+    /// A template with no schedule, used on demand
+    Unscheduled,
+}
+
+/// `IntervalType` Enum
+///
+/// Unit of a recurrence schedule's interval
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, Default)]
+pub enum IntervalType {
+    Daily,
+    Weekly,
+    #[default]
+    Monthly,
+    Yearly,
+}
+
+/// `DayOfWeek` Enum
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum DayOfWeek {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl From<DayOfWeek> for Weekday {
+    fn from(day: DayOfWeek) -> Self {
+        match day {
+            DayOfWeek::Monday => Weekday::Mon,
+            DayOfWeek::Tuesday => Weekday::Tue,
+            DayOfWeek::Wednesday => Weekday::Wed,
+            DayOfWeek::Thursday => Weekday::Thu,
+            DayOfWeek::Friday => Weekday::Fri,
+            DayOfWeek::Saturday => Weekday::Sat,
+            DayOfWeek::Sunday => Weekday::Sun,
+        }
+    }
+}
+
+/// `WeekOfMonth` Enum
+///
+/// Which `DayOfWeek` of the month a monthly or yearly schedule falls on
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum WeekOfMonth {
+    First,
+    Second,
+    Third,
+    Fourth,
+    Last,
+}
+
+/// `MonthOfYear` Enum
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum MonthOfYear {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+/// `RecurringInfo`
+///
+/// Recurrence settings of the transaction inside a [`RecurringTransaction`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct RecurringInfo {
+    /// Name of the recurring template
+    pub name: Option<String>,
+    /// How each occurrence is handled
+    pub recur_type: Option<RecurType>,
+    /// Indicates if the template is active
+    pub active: Option<bool>,
+    /// When the transaction recurs; absent for `Unscheduled` templates
+    pub schedule_info: Option<ScheduleInfo>,
+}
+
+impl RecurringInfo {
+    fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require("Name", &self.name)
+            .require("RecurType", &self.recur_type);
+        if self
+            .recur_type
+            .is_some_and(|recur| recur != RecurType::Unscheduled)
+        {
+            v.require_because(
+                "ScheduleInfo",
+                &self.schedule_info,
+                "when RecurType is Automated or Reminded",
+            );
+        }
+        if let Some(schedule) = &self.schedule_info {
+            v.nested("ScheduleInfo", schedule.validate());
+        }
+        v.finish()
+    }
+}
+
+/// `ScheduleInfo`
+///
+/// When a recurring transaction occurs: every `num_interval` days, weeks, months or years from
+/// `start_date`, until `end_date` or `max_occurrences`. Monthly and yearly schedules fall on
+/// `day_of_month`, or on the `week_of_month`/`day_of_week` pair.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ScheduleInfo {
+    /// Unit of the interval
+    pub interval_type: Option<IntervalType>,
+    /// Number of `interval_type` units between occurrences
+    pub num_interval: Option<u32>,
+    /// Day of the month for monthly and yearly schedules
+    pub day_of_month: Option<u32>,
+    /// Day of the week for weekly schedules, or with `week_of_month`
+    pub day_of_week: Option<DayOfWeek>,
+    /// Week of the month, with `day_of_week`
+    pub week_of_month: Option<WeekOfMonth>,
+    /// Month of the year for yearly schedules
+    pub month_of_year: Option<MonthOfYear>,
+    /// Days in advance each `Automated` transaction is created
+    pub days_before: Option<u32>,
+    /// Days in advance of each `Reminded` occurrence the reminder is shown
+    pub remind_days: Option<u32>,
+    /// Number of occurrences after which the schedule ends
+    pub max_occurrences: Option<u32>,
+    /// Date of the first occurrence
+    pub start_date: Option<NaiveDate>,
+    /// Date after which the schedule ends
+    pub end_date: Option<NaiveDate>,
+    /// Date of the next occurrence, set by `QuickBooks`
+    pub next_date: Option<NaiveDate>,
+    /// Date of the last occurrence, set by `QuickBooks`
+    pub previous_date: Option<NaiveDate>,
+}
+
+impl ScheduleInfo {
+    /// The first `n` occurrence dates on or after `from`.
+    ///
+    /// Occurrences are counted from `start_date`, so `max_occurrences` includes those before
+    /// `from`. Returns an empty list without a `start_date` or `interval_type`.
+    #[must_use]
+    pub fn next_occurrences(&self, from: NaiveDate, n: usize) -> Vec<NaiveDate> {
+        let Some(start) = self.start_date else {
+            return Vec::new();
+        };
+        // Intervals wholly before `from` are skipped, but still count towards `max_occurrences`;
+        // only the first interval can fall before `start`.
+        let skip = self.intervals_before(start, from);
+        let skipped =
+            skip - u32::from(skip > 0 && self.nth_date(start, 0).is_some_and(|date| date < start));
+        let max = self
+            .max_occurrences
+            .and_then(|max| usize::try_from(max.saturating_sub(skipped)).ok())
+            .unwrap_or(usize::MAX);
+        (skip..)
+            .map_while(|k| self.nth_date(start, k))
+            .filter(|date| *date >= start)
+            .take_while(|date| self.end_date.is_none_or(|end| *date <= end))
+            .take(max)
+            .filter(|date| *date >= from)
+            .take(n)
+            .collect()
+    }
+
+    /// Number of intervals after `start` whose dates are certain to precede `from`.
+    fn intervals_before(&self, start: NaiveDate, from: NaiveDate) -> u32 {
+        let days = (from - start).num_days();
+        let months = i64::from(from.year() - start.year()) * 12 + i64::from(from.month())
+            - i64::from(start.month());
+        let units = match self.interval_type {
+            Some(IntervalType::Daily) => days,
+            Some(IntervalType::Weekly) => days / 7,
+            Some(IntervalType::Monthly) => months,
+            Some(IntervalType::Yearly) => months / 12,
+            None => 0,
+        };
+        // One interval less, as the scheduled day within an interval may come after `from`
+        let k = units / i64::from(self.num_interval.unwrap_or(1).max(1)) - 1;
+        u32::try_from(k.max(0)).unwrap_or(u32::MAX)
+    }
+
+    /// Date in the `k`th interval after `start`, which may precede `start` in the first one.
+    fn nth_date(&self, start: NaiveDate, k: u32) -> Option<NaiveDate> {
+        let k = k.checked_mul(self.num_interval.unwrap_or(1).max(1))?;
+        match self.interval_type? {
+            IntervalType::Daily => start.checked_add_days(Days::new(k.into())),
+            IntervalType::Weekly => {
+                let offset = self
+                    .day_of_week
+                    .map_or(0, |day| days_until(start.weekday(), day.into()));
+                start.checked_add_days(Days::new(u64::from(offset) + u64::from(k) * 7))
+            }
+            IntervalType::Monthly => {
+                let month = start.with_day(1)?.checked_add_months(Months::new(k))?;
+                self.day_in_month(month, start)
+            }
+            IntervalType::Yearly => {
+                let first = match self.month_of_year {
+                    Some(month) => NaiveDate::from_ymd_opt(start.year(), month as u32 + 1, 1)?,
+                    None => start.with_day(1)?,
+                };
+                let month = first.checked_add_months(Months::new(k.checked_mul(12)?))?;
+                self.day_in_month(month, start)
+            }
+        }
+    }
+
+    /// Scheduled day of the month starting at `first`, clamped to the month's length.
+    fn day_in_month(&self, first: NaiveDate, start: NaiveDate) -> Option<NaiveDate> {
+        let last = first.checked_add_months(Months::new(1))?.pred_opt()?;
+        match (self.week_of_month, self.day_of_week) {
+            (Some(WeekOfMonth::Last), Some(day)) => {
+                last.checked_sub_days(Days::new(days_until(day.into(), last.weekday()).into()))
+            }
+            (Some(week), Some(day)) => NaiveDate::from_weekday_of_month_opt(
+                first.year(),
+                first.month(),
+                day.into(),
+                week as u8 + 1,
+            ),
+            _ => first.with_day(
+                self.day_of_month
+                    .unwrap_or(start.day())
+                    .clamp(1, last.day()),
+            ),
+        }
+    }
+
+    fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require("IntervalType", &self.interval_type)
+            .require("StartDate", &self.start_date)
+            .check(
+                self.num_interval != Some(0),
+                "NumInterval",
+                ValidationRule::InvalidValue,
+                "NumInterval must be at least 1",
+            );
+        if let (Some(start), Some(end)) = (self.start_date, self.end_date) {
+            v.check(
+                end >= start,
+                "EndDate",
+                ValidationRule::InvalidValue,
+                "EndDate must not be before StartDate",
+            );
+        }
+        v.finish()
+    }
+}
+
+/// Days from `from` forward to the next `to`, 0 if they are the same day.
+fn days_until(from: Weekday, to: Weekday) -> u32 {
+    (7 + to.num_days_from_monday() - from.num_days_from_monday()) % 7
+}
+
+/// `RecurringTransaction`
+///
+/// Represents a template that `QuickBooks` uses to create a transaction on a schedule. It wraps
+/// the template transaction, whose `recurring_info` holds the name and schedule, and serializes
+/// as `{"<Transaction>": {...}}`. Transactions created from it point back through
+/// `recur_data_ref`.
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires the wrapped transaction's creation requirements and
+///   `RecurringInfo` with `name`, `recur_type` and, unless `Unscheduled`, a `ScheduleInfo` with
+///   `interval_type` and `start_date`.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/recurringtransaction>
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum RecurringTransaction {
+    Invoice(Invoice),
+    Bill(Bill),
+    Estimate(Estimate),
+    SalesReceipt(SalesReceipt),
+    Purchase(Purchase),
+    JournalEntry(JournalEntry),
+}
+
+macro_rules! with_transaction {
+    ($value:expr, $txn:ident => $body:expr) => {
+        match $value {
+            RecurringTransaction::Invoice($txn) => $body,
+            RecurringTransaction::Bill($txn) => $body,
+            RecurringTransaction::Estimate($txn) => $body,
+            RecurringTransaction::SalesReceipt($txn) => $body,
+            RecurringTransaction::Purchase($txn) => $body,
+            RecurringTransaction::JournalEntry($txn) => $body,
+        }
+    };
+}
+
+impl RecurringTransaction {
+    /// Entity name of the wrapped transaction, e.g. `"Invoice"`.
+    #[must_use]
+    pub fn transaction_type(&self) -> &'static str {
+        fn name_of<T: QBItem>(_: &T) -> &'static str {
+            T::name()
+        }
+        with_transaction!(self, txn => name_of(txn))
+    }
+
+    #[must_use]
+    pub fn recurring_info(&self) -> Option<&RecurringInfo> {
+        with_transaction!(self, txn => txn.recurring_info.as_ref())
+    }
+
+    pub fn recurring_info_mut(&mut self) -> &mut Option<RecurringInfo> {
+        with_transaction!(self, txn => &mut txn.recurring_info)
+    }
+
+    /// The first `n` occurrence dates on or after `from`; empty for inactive or unscheduled
+    /// templates. See [`ScheduleInfo::next_occurrences`].
+    #[must_use]
+    pub fn next_occurrences(&self, from: NaiveDate, n: usize) -> Vec<NaiveDate> {
+        match self.recurring_info() {
+            Some(info) if info.active != Some(false) => info
+                .schedule_info
+                .as_ref()
+                .map(|schedule| schedule.next_occurrences(from, n))
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl Default for RecurringTransaction {
+    fn default() -> Self {
+        RecurringTransaction::Invoice(Invoice::default())
+    }
+}
+
+macro_rules! impl_from_transaction {
+    ($($x:ident),+) => {
+        $(
+            impl From<$x> for RecurringTransaction {
+                fn from(value: $x) -> Self {
+                    RecurringTransaction::$x(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_transaction!(
+    Invoice,
+    Bill,
+    Estimate,
+    SalesReceipt,
+    Purchase,
+    JournalEntry
+);
+
+impl QBItem for RecurringTransaction {
+    fn id(&self) -> Option<&String> {
+        with_transaction!(self, txn => txn.id.as_ref())
+    }
+
+    fn clone_id(&self) -> Option<String> {
+        self.id().cloned()
+    }
+
+    fn sync_token(&self) -> Option<&String> {
+        with_transaction!(self, txn => txn.sync_token.as_ref())
+    }
+
+    fn meta_data(&self) -> Option<&MetaData> {
+        with_transaction!(self, txn => txn.meta_data.as_ref())
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        "RecurringTransaction"
+    }
+
+    #[inline]
+    fn qb_id() -> &'static str {
+        "recurringtransaction"
+    }
+}
+
+impl Display for RecurringTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} : {}",
+            Self::name(),
+            serde_json::to_string_pretty(self).expect("Could not serialize object for display!")
+        )
+    }
+}
+
+impl QBCreatable for RecurringTransaction {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require("RecurringInfo", &self.recurring_info());
+        if let Some(info) = self.recurring_info() {
+            v.nested("RecurringInfo", info.validate());
+        }
+        v.nested("", with_transaction!(self, txn => txn.validate_create()));
+        Validator::new()
+            .nested(self.transaction_type(), v.finish())
+            .finish()
+    }
+}
+
+impl QBDeletable for RecurringTransaction {
+    /// `POST /recurringtransaction?operation=delete` with the reference wrapped in its
+    /// transaction type.
+    fn delete_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError> {
+        request::precondition::<Self>(self.can_delete(), "delete")?;
+        let body = json!({
+            self.transaction_type(): { "Id": self.id(), "SyncToken": self.sync_token() }
+        });
+        QBRequest::post_entity::<Self>(realm_id, &body, &[("operation", "delete")])
+    }
+}
+
+impl QBFullUpdatable for RecurringTransaction {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn expands_monthly_and_weekly_schedules() {
+        let mut schedule = ScheduleInfo {
+            interval_type: Some(IntervalType::Monthly),
+            day_of_month: Some(31),
+            start_date: Some(date(2024, 1, 15)),
+            max_occurrences: Some(4),
+            ..Default::default()
+        };
+        assert_eq!(
+            schedule.next_occurrences(date(2024, 2, 1), 10),
+            [date(2024, 2, 29), date(2024, 3, 31), date(2024, 4, 30)]
+        );
+
+        schedule.week_of_month = Some(WeekOfMonth::Last);
+        schedule.day_of_week = Some(DayOfWeek::Friday);
+        schedule.num_interval = Some(2);
+        assert_eq!(
+            schedule.next_occurrences(date(2024, 1, 1), 2),
+            [date(2024, 1, 26), date(2024, 3, 29)]
+        );
+
+        let weekly = ScheduleInfo {
+            interval_type: Some(IntervalType::Weekly),
+            day_of_week: Some(DayOfWeek::Monday),
+            start_date: Some(date(2024, 1, 3)),
+            end_date: Some(date(2024, 1, 22)),
+            ..Default::default()
+        };
+        assert_eq!(
+            weekly.next_occurrences(date(2024, 1, 1), 5),
+            [date(2024, 1, 8), date(2024, 1, 15), date(2024, 1, 22)]
+        );
+
+        // Far-off dates jump to the first interval instead of walking every one before
+        let daily = ScheduleInfo {
+            interval_type: Some(IntervalType::Daily),
+            num_interval: Some(3),
+            start_date: Some(date(2024, 1, 1)),
+            ..Default::default()
+        };
+        assert_eq!(
+            daily.next_occurrences(date(200_000, 1, 1), 2),
+            [date(200_000, 1, 1), date(200_000, 1, 4)]
+        );
+        let capped = ScheduleInfo {
+            max_occurrences: Some(5),
+            ..daily
+        };
+        assert_eq!(
+            capped.next_occurrences(date(2024, 1, 10), 5),
+            [date(2024, 1, 10), date(2024, 1, 13)]
+        );
+        assert!(capped.next_occurrences(date(200_000, 1, 1), 2).is_empty());
+    }
+
+    #[test]
+    fn wraps_the_template_transaction() {
+        let recurring: RecurringTransaction = serde_json::from_value(json!({
+            "Bill": {
+                "Id": "12",
+                "SyncToken": "0",
+                "VendorRef": { "value": "41" },
+                "Line": [{
+                    "Amount": 100.0,
+                    "DetailType": "AccountBasedExpenseLineDetail",
+                    "AccountBasedExpenseLineDetail": { "AccountRef": { "value": "7" } }
+                }],
+                "RecurringInfo": {
+                    "Name": "Rent",
+                    "RecurType": "Automated",
+                    "Active": true,
+                    "ScheduleInfo": {
+                        "IntervalType": "Monthly",
+                        "NumInterval": 1,
+                        "DayOfMonth": 1,
+                        "StartDate": "2024-01-01"
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(recurring.transaction_type(), "Bill");
+        assert_eq!(recurring.id().map(String::as_str), Some("12"));
+        assert!(recurring.can_full_update());
+        assert_eq!(
+            recurring.next_occurrences(date(2024, 3, 2), 1),
+            [date(2024, 4, 1)]
+        );
+
+        let mut unnamed = recurring.clone();
+        unnamed.recurring_info_mut().as_mut().unwrap().name = None;
+        let issues = unnamed.validate_create().unwrap_err();
+        assert_eq!(issues[0].path, "Bill.RecurringInfo.Name");
+
+        let request = recurring.delete_request("1234").unwrap();
+        assert_eq!(
+            request.body.unwrap(),
+            json!({ "Bill": { "Id": "12", "SyncToken": "0" } })
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{
+    Addr, CheckPayment, CreditCardPayment, CustomField, Email, GlobalTaxCalculation, MetaData,
+    NtRef, PrintStatus, TxnTaxDetail,
+};
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
+use crate::{
+    validation::Validator, LineField, QBCreatable, QBDeletable, QBFullUpdatable, QBPDFable,
+    QBSendable, QBSparseUpdateable, ValidationIssue,
+};
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// `RefundReceipt`
+///
+/// Represents a refund paid out to a customer, from the account in `deposit_to_account_ref`, by cash,
+/// check (`check_payment`) or credit card (`credit_card_payment`).
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires `deposit_to_account_ref` and at least one valid line.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/refundreceipt>
+pub struct RefundReceipt {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the transaction. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Reference to the customer being refunded
+    pub customer_ref: Option<NtRef>,
+    /// Line items for the refund
+    pub line: Option<LineField>,
+    /// Account the refund is paid from
+    pub deposit_to_account_ref: Option<NtRef>,
+    /// Reference to the payment method
+    pub payment_method_ref: Option<NtRef>,
+    /// Reference number for the payment
+    pub payment_ref_num: Option<String>,
+    /// Information about a check refund
+    pub check_payment: Option<CheckPayment>,
+    /// Information about a credit card refund
+    pub credit_card_payment: Option<CreditCardPayment>,
+    /// Total amount of the refund
+    pub total_amt: Option<f64>,
+    /// Total amount in home currency
+    pub home_total_amt: Option<f64>,
+    /// Current balance of the refund receipt
+    pub balance: Option<f64>,
+    /// Date of the transaction in YYYY-MM-DD format
+    pub txn_date: Option<NaiveDate>,
+    /// Document number for the refund receipt
+    pub doc_number: Option<String>,
+    /// Private note for the transaction
+    pub private_note: Option<String>,
+    /// Memo for the customer
+    pub customer_memo: Option<NtRef>,
+    /// Billing address for the refund receipt
+    pub bill_addr: Option<Addr>,
+    /// Shipping address for the refund receipt
+    pub ship_addr: Option<Addr>,
+    /// Email address for billing
+    pub bill_email: Option<Email>,
+    /// Print status of the refund receipt
+    pub print_status: Option<PrintStatus>,
+    /// Tax details for the transaction
+    pub txn_tax_detail: Option<TxnTaxDetail>,
+    /// Indicates if tax is applied after discount
+    pub apply_tax_after_discount: Option<bool>,
+    /// Method in which tax is applied
+    pub global_tax_calculation: Option<GlobalTaxCalculation>,
+    /// Reference to the class for the transaction
+    pub class_ref: Option<NtRef>,
+    /// Reference to the department for the transaction
+    pub department_ref: Option<NtRef>,
+    /// Reference to the currency for the transaction
+    pub currency_ref: Option<NtRef>,
+    /// Exchange rate for the transaction
+    pub exchange_rate: Option<f64>,
+    /// Custom fields for the entity
+    pub custom_field: Option<Vec<CustomField>>,
+}
+
+impl QBCreatable for RefundReceipt {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require("DepositToAccountRef", &self.deposit_to_account_ref)
+            .nested("Line", self.line.validate_create())
+            .finish()
+    }
+}
+
+impl QBDeletable for RefundReceipt {}
+
+impl QBFullUpdatable for RefundReceipt {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+impl QBSparseUpdateable for RefundReceipt {
+    fn can_sparse_update(&self) -> bool {
+        self.can_full_update()
+    }
+}
+
+impl QBSendable for RefundReceipt {}
+impl QBPDFable for RefundReceipt {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Line, LineDetail, SalesItemLineDetail};
+
+    #[test]
+    fn validates_and_serializes_refund() {
+        let mut refund = RefundReceipt {
+            customer_ref: Some(("Amy's Bird Sanctuary", "1").into()),
+            line: Some(vec![Line {
+                amount: Some(25.0),
+                line_detail: LineDetail::SalesItemLineDetail(SalesItemLineDetail {
+                    item_ref: Some(("Pump", "11").into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let issues = refund.validate_create().unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "DepositToAccountRef");
+
+        refund.deposit_to_account_ref = Some(("Checking", "35").into());
+        assert!(refund.can_create());
+        assert!(!refund.can_full_update());
+
+        let json = serde_json::to_value(&refund).unwrap();
+        assert_eq!(json["DepositToAccountRef"]["value"], "35");
+        assert_eq!(json["Line"][0]["DetailType"], "SalesItemLineDetail");
+        assert_eq!(
+            serde_json::from_value::<RefundReceipt>(json).unwrap(),
+            refund
+        );
+    }
+}
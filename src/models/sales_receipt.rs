@@ -9,10 +9,10 @@ use super::{
     },
     LineField,
 };
-#[cfg(feature = "builder")]
 use crate::error::QBTypeError;
 use crate::{
-    QBCreatable, QBFullUpdatable, QBItem, QBPDFable, QBSendable, QBSparseUpdateable, QBVoidable,
+    request::QBRequest, validation::Validator, QBCreatable, QBFullUpdatable, QBPDFable, QBSendable,
+    QBSparseUpdateable, QBVoidable, RecurringInfo, ValidationIssue,
 };
 
 #[skip_serializing_none]
@@ -101,10 +101,14 @@ pub struct SalesReceipt {
     pub meta_data: Option<MetaData>,
     /// Home balance for the transaction
     pub home_balance: Option<f64>,
+    /// Total amount in home currency
+    pub home_total_amt: Option<f64>,
     /// Delivery information for the transaction
     pub delivery_info: Option<DeliveryInfo>,
     /// Reference to the recurring data for the transaction
     pub recur_data_ref: Option<NtRef>,
+    /// Recurrence settings, set only on the template inside a `RecurringTransaction`
+    pub recurring_info: Option<RecurringInfo>,
     /// Total amount of the transaction
     pub total_amt: Option<f64>,
     /// Balance for the transaction
@@ -116,15 +120,24 @@ pub struct SalesReceipt {
 }
 
 impl QBCreatable for SalesReceipt {
-    fn can_create(&self) -> bool {
-        self.line.can_create()
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .nested("Line", self.line.validate_create())
+            .finish()
     }
 }
 
-impl QBVoidable for SalesReceipt {}
+impl QBVoidable for SalesReceipt {
+    fn void_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError> {
+        QBRequest::void_via_update(realm_id, self)
+    }
+}
 impl QBFullUpdatable for SalesReceipt {
-    fn can_full_update(&self) -> bool {
-        self.can_create() && self.has_read()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
     }
 }
 
@@ -0,0 +1,62 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::MetaData;
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
+use crate::{validation::Validator, QBCreatable, ValidationIssue};
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// `TaxAgency`
+///
+/// Represents a government agency that sales tax is collected for and paid to. Each `TaxRate`
+/// references its agency through `agency_ref`; new rates name their agency by ID in a
+/// [`TaxService`](crate::TaxService) request.
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires `display_name`.
+///
+/// Update semantics:
+/// - Tax agencies can only be created and read.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/taxagency>
+pub struct TaxAgency {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the entity. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Name of the agency
+    pub display_name: Option<String>,
+    /// Registration number the company files under with the agency
+    pub tax_registration_number: Option<String>,
+    /// Indicates if tax is tracked on sales
+    pub tax_tracked_on_sales: Option<bool>,
+    /// Indicates if tax is tracked on purchases
+    pub tax_tracked_on_purchases: Option<bool>,
+    /// Whether the agency is system generated or user defined
+    pub tax_agency_config: Option<String>,
+    /// Last date a tax return was filed with the agency
+    pub last_file_date: Option<NaiveDate>,
+}
+
+impl QBCreatable for TaxAgency {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require("DisplayName", &self.display_name)
+            .finish()
+    }
+}
@@ -1,10 +1,9 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::{
-    common::{MetaData, NtRef},
-    QBQueryable, QBReadable,
-};
+use crate::common::{MetaData, NtRef};
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
@@ -14,6 +13,14 @@ use crate::{
     derive(Builder),
     builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
 )]
+/// `TaxCode`
+///
+/// Represents a named set of sales and purchase tax rates applied to transactions and lines.
+/// Tax codes are read-only through this endpoint; create them (and any new `TaxRate`s) with a
+/// [`TaxService`](crate::TaxService) request.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/taxcode>
 pub struct TaxCode {
     /// The unique ID of the entity
     pub id: Option<String>,
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::common::{MetaData, NtRef};
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
@@ -12,6 +14,14 @@ use crate::common::{MetaData, NtRef};
     derive(Builder),
     builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
 )]
+/// `TaxRate`
+///
+/// Represents a single tax percentage paid to a `TaxAgency`. Tax rates are read-only through this
+/// endpoint; new rates are created as part of a tax code with a [`TaxService`](crate::TaxService)
+/// request.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/taxrate>
 pub struct TaxRate {
     /// The unique ID of the entity
     pub id: Option<String>,
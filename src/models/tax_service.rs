@@ -0,0 +1,295 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr, PickFirst};
+
+use super::common::NtRef;
+use crate::{
+    request::{Method, QBRequest},
+    validation::Validator,
+    QBCreatable, QBTypeError, TaxAgency, TaxCode, TaxRate, TaxRateDetail, ValidationIssue,
+    ValidationRule,
+};
+
+/// `TaxApplicableOn` Enum
+///
+/// Whether a tax rate in a tax code applies to sales or purchases
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, Default)]
+pub enum TaxApplicableOn {
+    #[default]
+    Sales,
+    Purchase,
+}
+
+/// `TaxService`
+///
+/// Request and response body of `POST /taxservice/taxcode`, the only way to create a `TaxCode`
+/// and new `TaxRate`s. The request names the code and lists its rates; the response echoes them
+/// with `tax_code_id` and every `tax_rate_id` filled in.
+///
+/// ```
+/// use quickbooks_types::{QBCreatable, TaxAgency, TaxApplicableOn, TaxService, TaxServiceRate};
+///
+/// let agency = TaxAgency {
+///     id: Some("1".into()),
+///     ..Default::default()
+/// };
+/// let service = TaxService::new("County").with_rate(TaxServiceRate::new_rate(
+///     "County Tax",
+///     2.5,
+///     &agency,
+///     TaxApplicableOn::Sales,
+/// ));
+///
+/// assert!(service.can_create());
+/// let request = service.create_request("1234").unwrap();
+/// assert_eq!(request.path, "/v3/company/1234/taxservice/taxcode");
+/// ```
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/taxservice>
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct TaxService {
+    /// Name of the tax code
+    pub tax_code: Option<String>,
+    /// ID of the created tax code, set in the response
+    pub tax_code_id: Option<String>,
+    /// Rates making up the tax code
+    pub tax_rate_details: Option<Vec<TaxServiceRate>>,
+}
+
+/// `TaxServiceRate`
+///
+/// One rate of a [`TaxService`] request: either an existing `TaxRate` by `tax_rate_id`, or a new
+/// rate with a name, value and agency.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct TaxServiceRate {
+    /// ID of an existing tax rate, or of the created rate in the response
+    pub tax_rate_id: Option<String>,
+    /// Name of a new tax rate
+    pub tax_rate_name: Option<String>,
+    /// Rate percentage of a new tax rate
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    pub rate_value: Option<f64>,
+    /// ID of the agency a new tax rate is paid to
+    pub tax_agency_id: Option<String>,
+    /// Whether the rate applies to sales or purchases
+    pub tax_applicable_on: Option<TaxApplicableOn>,
+}
+
+impl TaxServiceRate {
+    /// Adds an existing tax rate to the code.
+    #[must_use]
+    pub fn from_rate(rate: &TaxRate, applicable_on: TaxApplicableOn) -> Self {
+        Self {
+            tax_rate_id: rate.id.clone(),
+            tax_applicable_on: Some(applicable_on),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new tax rate of `rate_value` percent, paid to `agency`.
+    #[must_use]
+    pub fn new_rate(
+        name: impl Into<String>,
+        rate_value: f64,
+        agency: &TaxAgency,
+        applicable_on: TaxApplicableOn,
+    ) -> Self {
+        Self {
+            tax_rate_name: Some(name.into()),
+            rate_value: Some(rate_value),
+            tax_agency_id: agency.id.clone(),
+            tax_applicable_on: Some(applicable_on),
+            ..Default::default()
+        }
+    }
+
+    fn tax_rate_detail(&self) -> TaxRateDetail {
+        TaxRateDetail {
+            tax_rate_ref: NtRef {
+                name: self.tax_rate_name.clone(),
+                value: self.tax_rate_id.clone(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
+
+impl TaxService {
+    /// Starts a request for a tax code named `name`.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            tax_code: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a rate to the tax code.
+    #[must_use]
+    pub fn with_rate(mut self, rate: TaxServiceRate) -> Self {
+        self.tax_rate_details
+            .get_or_insert_with(Vec::new)
+            .push(rate);
+        self
+    }
+
+    /// `POST /taxservice/taxcode` creating the tax code; fails if
+    /// [`validate_create()`](QBCreatable::validate_create) does.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QBTypeError::ValidationFailed` listing every missing requirement.
+    pub fn create_request(&self, realm_id: &str) -> Result<QBRequest, QBTypeError> {
+        self.validate_create()
+            .map_err(|issues| QBTypeError::ValidationFailed {
+                entity: "TaxService",
+                operation: "create",
+                issues,
+            })?;
+        let body = serde_json::to_value(self).map_err(|e| QBTypeError::InvalidPayload {
+            entity: "TaxService",
+            message: e.to_string(),
+        })?;
+        Ok(QBRequest::new(Method::Post, realm_id, "taxservice/taxcode").json(body))
+    }
+
+    /// The created `TaxCode`, with its rates split into sales and purchase lists.
+    ///
+    /// Returns `None` for a request, which has no `tax_code_id` yet.
+    #[must_use]
+    pub fn tax_code(&self) -> Option<TaxCode> {
+        let id = self.tax_code_id.clone()?;
+        let details = |on| {
+            let list: Vec<_> = self
+                .tax_rate_details
+                .iter()
+                .flatten()
+                .filter(|rate| rate.tax_applicable_on == Some(on))
+                .map(TaxServiceRate::tax_rate_detail)
+                .collect();
+            (!list.is_empty()).then_some(list)
+        };
+        let sales_tax_rate_list = details(TaxApplicableOn::Sales);
+        Some(TaxCode {
+            id: Some(id),
+            name: self.tax_code.clone(),
+            active: Some(true),
+            taxable: Some(sales_tax_rate_list.is_some()),
+            sales_tax_rate_list,
+            purchase_tax_rate_list: details(TaxApplicableOn::Purchase),
+            ..Default::default()
+        })
+    }
+
+    /// The tax rates created by this request, as returned in the response.
+    ///
+    /// Rates that already existed are included only by ID and name.
+    #[must_use]
+    pub fn tax_rates(&self) -> Vec<TaxRate> {
+        self.tax_rate_details
+            .iter()
+            .flatten()
+            .filter(|rate| rate.tax_rate_id.is_some())
+            .map(|rate| TaxRate {
+                id: rate.tax_rate_id.clone(),
+                name: rate.tax_rate_name.clone(),
+                rate_value: rate.rate_value,
+                agency_ref: rate.tax_agency_id.clone().map(|id| NtRef {
+                    value: Some(id),
+                    ..Default::default()
+                }),
+                active: Some(true),
+                ..Default::default()
+            })
+            .collect()
+    }
+}
+
+impl QBCreatable for TaxService {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require("TaxCode", &self.tax_code).check(
+            self.tax_rate_details
+                .as_ref()
+                .is_some_and(|rates| !rates.is_empty()),
+            "TaxRateDetails",
+            ValidationRule::Required,
+            "at least one tax rate is required",
+        );
+        for (i, rate) in self.tax_rate_details.iter().flatten().enumerate() {
+            let mut r = Validator::new();
+            r.require("TaxApplicableOn", &rate.tax_applicable_on);
+            if rate.tax_rate_id.is_none() {
+                const REASON: &str = "when TaxRateId is not set";
+                r.require_because("TaxRateName", &rate.tax_rate_name, REASON)
+                    .require_because("RateValue", &rate.rate_value, REASON)
+                    .require_because("TaxAgencyId", &rate.tax_agency_id, REASON);
+            }
+            v.nested(&format!("TaxRateDetails[{i}]"), r.finish());
+        }
+        v.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn response_links_to_tax_code_and_rates() {
+        let response: TaxService = serde_json::from_value(serde_json::json!({
+            "TaxCode": "County",
+            "TaxCodeId": "5",
+            "TaxRateDetails": [
+                {
+                    "TaxRateName": "County Tax",
+                    "TaxRateId": "7",
+                    "RateValue": "2.5",
+                    "TaxAgencyId": "1",
+                    "TaxApplicableOn": "Sales"
+                },
+                { "TaxRateId": "3", "TaxApplicableOn": "Purchase" }
+            ]
+        }))
+        .unwrap();
+
+        let code = response.tax_code().unwrap();
+        assert_eq!(code.id.as_deref(), Some("5"));
+        let sales = code.sales_tax_rate_list.unwrap();
+        assert_eq!(sales[0].tax_rate_ref.value.as_deref(), Some("7"));
+        assert_eq!(code.purchase_tax_rate_list.unwrap().len(), 1);
+
+        let rates = response.tax_rates();
+        assert_eq!(rates.len(), 2);
+        assert_eq!(rates[0].rate_value, Some(2.5));
+        assert_eq!(
+            rates[0].agency_ref.as_ref().unwrap().value.as_deref(),
+            Some("1")
+        );
+    }
+
+    #[test]
+    fn new_rates_need_name_value_and_agency() {
+        let service = TaxService::new("County").with_rate(TaxServiceRate {
+            tax_rate_name: Some("County Tax".into()),
+            ..Default::default()
+        });
+        let issues = service.validate_create().unwrap_err();
+        let paths: Vec<_> = issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "TaxRateDetails[0].TaxApplicableOn",
+                "TaxRateDetails[0].RateValue",
+                "TaxRateDetails[0].TaxAgencyId",
+            ]
+        );
+        assert!(service.tax_code().is_none());
+    }
+}
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::{common::MetaData, QBCreatable, QBFullUpdatable, QBItem};
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
+use crate::{
+    common::MetaData, validation::Validator, QBCreatable, QBFullUpdatable, ValidationIssue,
+};
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
@@ -59,13 +63,22 @@ pub enum TermType {
 }
 
 impl QBCreatable for Term {
-    fn can_create(&self) -> bool {
-        self.name.is_some() && (self.day_of_month_due.is_some() || self.due_days.is_some())
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require("Name", &self.name)
+            .require_one_of(
+                &["DueDays", "DayOfMonthDue"],
+                self.day_of_month_due.is_some() || self.due_days.is_some(),
+            )
+            .finish()
     }
 }
 
 impl QBFullUpdatable for Term {
-    fn can_full_update(&self) -> bool {
-        self.has_read() && self.can_create()
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
     }
 }
@@ -0,0 +1,296 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{LinkedTxn, MetaData, NtRef};
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
+use crate::{
+    validation::Validator, BillableStatus, Employee, Line, LineDetail, LineField, QBCreatable,
+    QBDeletable, QBFullUpdatable, SalesItemLineDetail, ValidationIssue, ValidationRule,
+};
+
+/// `NameOf` Enum
+///
+/// Whether a time activity was performed by an employee or a vendor
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, Default)]
+pub enum NameOf {
+    #[default]
+    Employee,
+    Vendor,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// `TimeActivity`
+///
+/// Represents time worked by an employee or vendor, optionally billable to a customer.
+/// Time is recorded either as `hours`/`minutes` or as `start_time`/`end_time` less `break_hours`/`break_minutes`.
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires `name_of` with the matching `employee_ref` or `vendor_ref`,
+///   and exactly one time representation.
+/// - Billable activities also need `customer_ref`.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/timeactivity>
+pub struct TimeActivity {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the transaction. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Date the work was performed
+    pub txn_date: Option<NaiveDate>,
+    /// Whether `employee_ref` or `vendor_ref` identifies who did the work
+    pub name_of: Option<NameOf>,
+    /// Reference to the employee, when `name_of` is `Employee`
+    pub employee_ref: Option<NtRef>,
+    /// Reference to the vendor, when `name_of` is `Vendor`
+    pub vendor_ref: Option<NtRef>,
+    /// Reference to the customer the work was done for
+    pub customer_ref: Option<NtRef>,
+    /// Reference to the service item the work is billed as
+    pub item_ref: Option<NtRef>,
+    /// Reference to the class for the transaction
+    pub class_ref: Option<NtRef>,
+    /// Reference to the department for the transaction
+    pub department_ref: Option<NtRef>,
+    /// Reference to the payroll item
+    pub payroll_item_ref: Option<NtRef>,
+    /// Billable status of the activity
+    pub billable_status: Option<BillableStatus>,
+    /// Indicates if the billed time is taxable
+    pub taxable: Option<bool>,
+    /// Hourly rate billed to the customer
+    pub hourly_rate: Option<f64>,
+    /// Hourly cost of the work
+    pub cost_rate: Option<f64>,
+    /// Hours worked (with `minutes`)
+    pub hours: Option<u32>,
+    /// Minutes worked (with `hours`)
+    pub minutes: Option<u32>,
+    /// Start of the work (with `end_time`)
+    pub start_time: Option<DateTime<FixedOffset>>,
+    /// End of the work (with `start_time`)
+    pub end_time: Option<DateTime<FixedOffset>>,
+    /// Hours of break taken between `start_time` and `end_time`
+    pub break_hours: Option<u32>,
+    /// Minutes of break taken between `start_time` and `end_time`
+    pub break_minutes: Option<u32>,
+    /// Description of the work
+    pub description: Option<String>,
+}
+
+impl TimeActivity {
+    /// Time worked in hours, from `hours`/`minutes` or from `start_time`/`end_time` less the break.
+    ///
+    /// Minutes are summed as `i64`, so any `u32` hours and minutes add up without overflow.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn duration_hours(&self) -> Option<f64> {
+        let total_minutes = |hours: Option<u32>, minutes: Option<u32>| {
+            i64::from(hours.unwrap_or_default()) * 60 + i64::from(minutes.unwrap_or_default())
+        };
+        if self.hours.is_some() || self.minutes.is_some() {
+            return Some(total_minutes(self.hours, self.minutes) as f64 / 60.0);
+        }
+        let worked = self.end_time? - self.start_time?;
+        let minutes = worked.num_minutes() - total_minutes(self.break_hours, self.break_minutes);
+        Some(minutes as f64 / 60.0)
+    }
+
+    /// Builds invoice lines for the billable activities that have not been billed yet.
+    ///
+    /// Each line bills `duration_hours()` of the activity's item at the performing employee's
+    /// `bill_rate` (looked up in `employees` by `employee_ref`), falling back to the activity's
+    /// `hourly_rate`. Activities with no rate or duration are skipped. Lines are linked to their
+    /// activity through `LinkedTxn`.
+    pub fn billable_lines<'a>(
+        activities: impl IntoIterator<Item = &'a TimeActivity>,
+        employees: &[Employee],
+    ) -> LineField {
+        activities
+            .into_iter()
+            .filter(|activity| activity.billable_status == Some(BillableStatus::Billable))
+            .filter_map(|activity| {
+                let employee_rate = activity
+                    .employee_ref
+                    .as_ref()
+                    .and_then(|employee_ref| {
+                        employees.iter().find(|employee| {
+                            employee.id.is_some() && employee.id == employee_ref.value
+                        })
+                    })
+                    .and_then(|employee| employee.bill_rate);
+                let rate = employee_rate.or(activity.hourly_rate)?;
+                let hours = activity.duration_hours()?;
+                Some(Line {
+                    amount: Some((hours * rate * 100.0).round() / 100.0),
+                    description: activity.description.clone(),
+                    line_detail: LineDetail::SalesItemLineDetail(SalesItemLineDetail {
+                        item_ref: activity.item_ref.clone(),
+                        class_ref: activity.class_ref.clone(),
+                        service_date: activity.txn_date,
+                        qty: Some(hours),
+                        unit_price: Some(rate),
+                        ..Default::default()
+                    }),
+                    linked_txn: LinkedTxn::from_entity(activity).map(|link| vec![link]),
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+}
+
+impl QBCreatable for TimeActivity {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require("NameOf", &self.name_of);
+        match self.name_of {
+            Some(NameOf::Employee) => {
+                v.require_because("EmployeeRef", &self.employee_ref, "when NameOf is Employee");
+            }
+            Some(NameOf::Vendor) => {
+                v.require_because("VendorRef", &self.vendor_ref, "when NameOf is Vendor");
+            }
+            None => {}
+        }
+        if self.billable_status == Some(BillableStatus::Billable) {
+            v.require_because(
+                "CustomerRef",
+                &self.customer_ref,
+                "when BillableStatus is Billable",
+            );
+        }
+
+        let duration = self.hours.is_some() || self.minutes.is_some();
+        let range = self.start_time.is_some() || self.end_time.is_some();
+        v.require_one_of(&["Hours", "Minutes", "StartTime"], duration || range)
+            .check(
+                !(duration && range),
+                "StartTime",
+                ValidationRule::InvalidValue,
+                "use either Hours/Minutes or StartTime/EndTime, not both",
+            );
+        if range {
+            v.require_because("StartTime", &self.start_time, "when EndTime is set")
+                .require_because("EndTime", &self.end_time, "when StartTime is set");
+        }
+        v.check(
+            range || (self.break_hours.is_none() && self.break_minutes.is_none()),
+            "BreakHours",
+            ValidationRule::InvalidValue,
+            "BreakHours/BreakMinutes only apply with StartTime/EndTime",
+        );
+        if let (Some(start), Some(end)) = (self.start_time, self.end_time) {
+            v.check(
+                end > start && self.duration_hours().is_some_and(|hours| hours >= 0.0),
+                "EndTime",
+                ValidationRule::InvalidValue,
+                "EndTime must be after StartTime plus any break",
+            );
+        }
+        v.finish()
+    }
+}
+
+impl QBDeletable for TimeActivity {}
+
+impl QBFullUpdatable for TimeActivity {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity() -> TimeActivity {
+        TimeActivity {
+            id: Some("5".into()),
+            txn_date: NaiveDate::from_ymd_opt(2024, 3, 4),
+            name_of: Some(NameOf::Employee),
+            employee_ref: Some(("Emily Platt", "55").into()),
+            customer_ref: Some(("Amy's Bird Sanctuary", "1").into()),
+            item_ref: Some(("Hours", "2").into()),
+            billable_status: Some(BillableStatus::Billable),
+            hourly_rate: Some(50.0),
+            hours: Some(1),
+            minutes: Some(30),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn enforces_a_single_time_representation() {
+        let mut time = activity();
+        assert!(time.can_create());
+
+        time.start_time = DateTime::parse_from_rfc3339("2024-03-04T08:00:00-08:00").ok();
+        let issues = time.validate_create().unwrap_err();
+        let paths: Vec<_> = issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, ["StartTime", "EndTime"]);
+
+        time.hours = None;
+        time.minutes = None;
+        time.end_time = DateTime::parse_from_rfc3339("2024-03-04T12:30:00-08:00").ok();
+        time.break_minutes = Some(30);
+        assert!(time.can_create());
+        assert_eq!(time.duration_hours(), Some(4.0));
+        let huge = TimeActivity {
+            hours: Some(u32::MAX),
+            minutes: Some(u32::MAX),
+            ..Default::default()
+        };
+        assert!(huge
+            .duration_hours()
+            .is_some_and(|hours| hours > f64::from(u32::MAX)));
+    }
+
+    #[test]
+    fn billable_lines_use_employee_bill_rate() {
+        let employees = [Employee {
+            id: Some("55".into()),
+            bill_rate: Some(80.0),
+            ..Default::default()
+        }];
+        let billed = TimeActivity {
+            billable_status: Some(BillableStatus::HasBeenBilled),
+            ..activity()
+        };
+        let lines = TimeActivity::billable_lines([&activity(), &billed], &employees);
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].amount, Some(120.0));
+        let LineDetail::SalesItemLineDetail(detail) = &lines[0].line_detail else {
+            panic!("expected a sales item line");
+        };
+        assert_eq!((detail.qty, detail.unit_price), (Some(1.5), Some(80.0)));
+        assert_eq!(
+            lines[0].linked_txn.as_ref().unwrap()[0].txn_type.as_deref(),
+            Some("TimeActivity")
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{MetaData, NtRef};
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
+use crate::{
+    validation::Validator, QBCreatable, QBDeletable, QBFullUpdatable, QBSparseUpdateable,
+    ValidationIssue, ValidationRule,
+};
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// Transfer
+///
+/// Represents a movement of funds between two balance sheet accounts (e.g. checking to savings).
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires `from_account_ref`, `to_account_ref` (a different account)
+///   and a positive `amount`.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/transfer>
+pub struct Transfer {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the transaction. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Account the funds are transferred from
+    pub from_account_ref: Option<NtRef>,
+    /// Account the funds are transferred to
+    pub to_account_ref: Option<NtRef>,
+    /// Amount transferred
+    pub amount: Option<f64>,
+    /// Date of the transaction in YYYY-MM-DD format
+    pub txn_date: Option<NaiveDate>,
+    /// Private note for the transaction
+    pub private_note: Option<String>,
+    /// Reference to the currency for the transaction
+    pub currency_ref: Option<NtRef>,
+    /// Exchange rate for the transaction
+    pub exchange_rate: Option<f64>,
+}
+
+impl QBCreatable for Transfer {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require("FromAccountRef", &self.from_account_ref)
+            .require("ToAccountRef", &self.to_account_ref)
+            .require("Amount", &self.amount)
+            .check(
+                self.amount.is_none_or(|amount| amount > 0.0),
+                "Amount",
+                ValidationRule::InvalidValue,
+                "Amount must be positive",
+            );
+        if let (Some(from), Some(to)) = (&self.from_account_ref, &self.to_account_ref) {
+            v.check(
+                from.value.is_none() || from.value != to.value,
+                "ToAccountRef",
+                ValidationRule::InvalidValue,
+                "ToAccountRef must differ from FromAccountRef",
+            );
+        }
+        v.finish()
+    }
+}
+
+impl QBDeletable for Transfer {}
+
+impl QBFullUpdatable for Transfer {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+impl QBSparseUpdateable for Transfer {
+    fn can_sparse_update(&self) -> bool {
+        self.can_full_update()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transfer_needs_distinct_accounts_and_positive_amount() {
+        let mut transfer = Transfer {
+            from_account_ref: Some(("Checking", "35").into()),
+            to_account_ref: Some(("Checking", "35").into()),
+            amount: Some(0.0),
+            ..Default::default()
+        };
+        let issues = transfer.validate_create().unwrap_err();
+        let paths: Vec<_> = issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, ["Amount", "ToAccountRef"]);
+        assert!(issues
+            .iter()
+            .all(|issue| issue.rule == ValidationRule::InvalidValue));
+
+        transfer.to_account_ref = Some(("Savings", "36").into());
+        transfer.amount = Some(-5.0);
+        assert_eq!(transfer.validate_create().unwrap_err()[0].path, "Amount");
+
+        transfer.amount = Some(125.0);
+        assert!(transfer.can_create());
+        assert!(!transfer.can_full_update());
+        assert_eq!(Transfer::default().validate_create().unwrap_err().len(), 3);
+    }
+}
@@ -0,0 +1,122 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{CustomField, GlobalTaxCalculation, LinkedTxn, MetaData, NtRef, TxnTaxDetail};
+#[cfg(feature = "builder")]
+use crate::error::QBTypeError;
+use crate::{
+    validation::Validator, LineDetail, LineField, QBCreatable, QBDeletable, QBFullUpdatable,
+    QBSparseUpdateable, ValidationIssue, ValidationRule,
+};
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+#[cfg_attr(
+    feature = "builder",
+    derive(Builder),
+    builder(default, build_fn(error = "QBTypeError"), setter(into, strip_option))
+)]
+/// `VendorCredit`
+///
+/// Represents a credit from a vendor (e.g. for returned goods), reducing what is owed on accounts payable.
+/// The open `balance` can be applied against bills in a `BillPayment` (see [`BillPayment::apply_vendor_credit`](crate::BillPayment::apply_vendor_credit)).
+///
+/// Creation requirements:
+/// - `QBCreatable::validate_create()` requires `vendor_ref` and at least one valid item or account expense line.
+///
+/// Update semantics:
+/// - `QBFullUpdatable::validate_update()` requires `has_read()` (ID + sync token) and the creation requirements.
+///
+/// API reference:
+/// <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/all-entities/vendorcredit>
+pub struct VendorCredit {
+    /// The unique ID of the entity
+    pub id: Option<String>,
+    /// The unique sync token of the entity, used for concurrency control
+    pub sync_token: Option<String>,
+    /// Metadata about the entity
+    #[serde(skip_serializing)]
+    pub meta_data: Option<MetaData>,
+    /// Domain of the transaction. `QBO` for `QuickBooks` Online.
+    pub domain: Option<String>,
+    /// Indicates if the entity is a sparse object
+    #[serde(rename = "sparse")]
+    pub sparse: Option<bool>,
+    /// Reference to the vendor issuing the credit
+    pub vendor_ref: Option<NtRef>,
+    /// Item and account expense lines for the credit
+    pub line: Option<LineField>,
+    /// Reference to the Accounts Payable account for the credit
+    #[serde(rename = "APAccountRef")]
+    pub ap_account_ref: Option<NtRef>,
+    /// Total amount of the credit
+    pub total_amt: Option<f64>,
+    /// Credit not yet applied to bills
+    pub balance: Option<f64>,
+    /// Date of the transaction in YYYY-MM-DD format
+    pub txn_date: Option<NaiveDate>,
+    /// Document number for the credit
+    pub doc_number: Option<String>,
+    /// Private note for the transaction
+    pub private_note: Option<String>,
+    /// Reference to the department for the transaction
+    pub department_ref: Option<NtRef>,
+    /// Reference to the currency for the transaction
+    pub currency_ref: Option<NtRef>,
+    /// Exchange rate for the transaction
+    pub exchange_rate: Option<f64>,
+    /// Tax details for the transaction
+    pub txn_tax_detail: Option<TxnTaxDetail>,
+    /// Method in which tax is applied
+    pub global_tax_calculation: Option<GlobalTaxCalculation>,
+    /// Linked transactions
+    pub linked_txn: Option<Vec<LinkedTxn>>,
+    /// Custom fields for the entity
+    pub custom_field: Option<Vec<CustomField>>,
+}
+
+impl QBCreatable for VendorCredit {
+    fn validate_create(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut v = Validator::new();
+        v.require("VendorRef", &self.vendor_ref)
+            .nested("Line", self.line.validate_create())
+            .check(
+                self.line.as_ref().is_none_or(|lines| !lines.is_empty()),
+                "Line",
+                ValidationRule::Required,
+                "at least one line is required",
+            );
+        for (i, line) in self.line.iter().flatten().enumerate() {
+            v.check(
+                matches!(
+                    line.line_detail,
+                    LineDetail::AccountBasedExpenseLineDetail(_)
+                        | LineDetail::ItemBasedExpenseLineDetail(_)
+                ),
+                &format!("Line[{i}].DetailType"),
+                ValidationRule::InvalidValue,
+                "vendor credit lines must use ItemBasedExpenseLineDetail or AccountBasedExpenseLineDetail",
+            );
+        }
+        v.finish()
+    }
+}
+
+impl QBDeletable for VendorCredit {}
+
+impl QBFullUpdatable for VendorCredit {
+    fn validate_update(&self) -> Result<(), Vec<ValidationIssue>> {
+        Validator::new()
+            .require_read(self)
+            .nested("", self.validate_create())
+            .finish()
+    }
+}
+
+impl QBSparseUpdateable for VendorCredit {
+    fn can_sparse_update(&self) -> bool {
+        self.can_full_update()
+    }
+}
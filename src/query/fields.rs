@@ -0,0 +1,365 @@
+//! Per-entity catalogs of queryable fields.
+//!
+//! Each entity gets a `<Entity>Field` type whose associated constants name the columns the
+//! API lets you filter, sort or select on. Every constant is a [`Field`] that carries the
+//! owning entity, the value type compared against, and its capability, so the query builder
+//! rejects unknown columns, wrong value types and unsortable `ORDERBY` columns at compile time.
+//!
+//! All catalogs include `Id`, `CreateTime` (`MetaData.CreateTime`) and `LastUpdatedTime`
+//! (`MetaData.LastUpdatedTime`).
+//!
+//! ```compile_fail
+//! use quickbooks_types::{query::InvoiceField, Invoice, QBQueryable};
+//!
+//! // `PrivateNote` can be selected but not filtered on
+//! let query = Invoice::query().where_eq(InvoiceField::PrivateNote, "rush");
+//! ```
+
+use std::marker::PhantomData;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::{
+    common::NtRef, Account, Attachable, AttachmentCategory, Bill, BillPayment, Budget, Class,
+    CompanyCurrency, CompanyInfo, CreditMemo, Customer, Department, Deposit, Employee, Estimate,
+    Exchangerate, InventoryAdjustment, Invoice, Item, ItemType, JournalEntry, POStatus, Payment,
+    PaymentMethod, PaymentMethodType, PaymentType, Preferences, Purchase, PurchaseOrder,
+    RecurringTransaction, RefundReceipt, SalesReceipt, TaxAgency, TaxCode, TaxRate, Term,
+    TimeActivity, Transfer, Vendor, VendorCredit,
+};
+
+/// Marker for fields that can be filtered on.
+pub trait CanFilter {}
+
+/// Marker for fields that can appear in `ORDERBY`.
+pub trait CanSort {}
+
+/// Capability of a field that can be both filtered and sorted on.
+#[derive(Clone, Copy, Debug)]
+pub struct FilterAndSort;
+
+/// Capability of a field that can be filtered on but not sorted.
+#[derive(Clone, Copy, Debug)]
+pub struct FilterOnly;
+
+/// Capability of a field that can only be projected with `select`.
+#[derive(Clone, Copy, Debug)]
+pub struct SelectOnly;
+
+impl CanFilter for FilterAndSort {}
+impl CanSort for FilterAndSort {}
+impl CanFilter for FilterOnly {}
+
+/// A queryable column of entity `E`, compared against values of type `V`, with capability `C`.
+pub struct Field<E, V, C> {
+    name: &'static str,
+    _entity: PhantomData<fn() -> E>,
+    _value: PhantomData<fn() -> V>,
+    _capability: PhantomData<C>,
+}
+
+impl<E, V, C> Field<E, V, C> {
+    pub(crate) const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _entity: PhantomData,
+            _value: PhantomData,
+            _capability: PhantomData,
+        }
+    }
+
+    /// Column name as it appears in a query, e.g. `MetaData.LastUpdatedTime`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<E, V, C> Clone for Field<E, V, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E, V, C> Copy for Field<E, V, C> {}
+
+impl<E, V, C> std::fmt::Debug for Field<E, V, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Field").field(&self.name).finish()
+    }
+}
+
+macro_rules! impl_field_catalog {
+    ($(
+        $entity:ident {
+            $($field:ident $(= $name:literal)?: $ty:ty, $cap:ident;)*
+        }
+    )*) => {
+        paste::paste! {
+            $(
+                #[doc = "Queryable fields of [`" $entity "`](crate::" $entity ")."]
+                #[derive(Clone, Copy, Debug)]
+                pub struct [<$entity Field>];
+
+                #[allow(non_upper_case_globals)]
+                impl [<$entity Field>] {
+                    pub const Id: Field<$entity, String, FilterAndSort> = Field::new("Id");
+                    pub const CreateTime: Field<$entity, DateTime<Utc>, FilterAndSort> =
+                        Field::new("MetaData.CreateTime");
+                    pub const LastUpdatedTime: Field<$entity, DateTime<Utc>, FilterAndSort> =
+                        Field::new("MetaData.LastUpdatedTime");
+                    $(
+                        pub const $field: Field<$entity, $ty, $cap> =
+                            Field::new(impl_field_catalog!(@name $field $($name)?));
+                    )*
+                }
+            )*
+        }
+    };
+    (@name $field:ident $name:literal) => { $name };
+    (@name $field:ident) => { stringify!($field) };
+}
+
+impl_field_catalog!(
+    Account {
+        Name: String, FilterAndSort;
+        AcctNum: String, FilterOnly;
+        AccountType: String, FilterOnly;
+        AccountSubType: String, FilterOnly;
+        Classification: String, FilterOnly;
+        FullyQualifiedName: String, FilterAndSort;
+        CurrentBalance: f64, FilterAndSort;
+        Active: bool, FilterOnly;
+        SubAccount: bool, FilterOnly;
+        ParentRef: NtRef, FilterOnly;
+    }
+    Attachable {
+        FileName: String, FilterAndSort;
+        ContentType: String, FilterOnly;
+        Category: AttachmentCategory, FilterOnly;
+        EntityRefType = "AttachableRef.EntityRef.Type": String, FilterOnly;
+        EntityRefValue = "AttachableRef.EntityRef.Value": String, FilterOnly;
+        Note: String, SelectOnly;
+    }
+    Bill {
+        DocNumber: String, FilterAndSort;
+        TxnDate: NaiveDate, FilterAndSort;
+        DueDate: NaiveDate, FilterAndSort;
+        VendorRef: NtRef, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        Balance: f64, FilterAndSort;
+        PrivateNote: String, SelectOnly;
+    }
+    BillPayment {
+        TxnDate: NaiveDate, FilterAndSort;
+        VendorRef: NtRef, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        PrivateNote: String, SelectOnly;
+    }
+    Budget {
+        Name: String, FilterAndSort;
+        StartDate: NaiveDate, FilterAndSort;
+        EndDate: NaiveDate, FilterAndSort;
+        Active: bool, FilterOnly;
+    }
+    Class {
+        Name: String, FilterAndSort;
+        FullyQualifiedName: String, FilterAndSort;
+        Active: bool, FilterOnly;
+        ParentRef: NtRef, FilterOnly;
+    }
+    CompanyCurrency {
+        Code: String, FilterAndSort;
+        Name: String, FilterAndSort;
+        Active: bool, FilterOnly;
+    }
+    CompanyInfo {
+        CompanyName: String, SelectOnly;
+        LegalName: String, SelectOnly;
+        Country: String, SelectOnly;
+    }
+    CreditMemo {
+        DocNumber: String, FilterAndSort;
+        TxnDate: NaiveDate, FilterAndSort;
+        CustomerRef: NtRef, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        RemainingCredit: f64, FilterAndSort;
+        PrivateNote: String, SelectOnly;
+    }
+    Customer {
+        DisplayName: String, FilterAndSort;
+        GivenName: String, FilterAndSort;
+        MiddleName: String, FilterAndSort;
+        FamilyName: String, FilterAndSort;
+        CompanyName: String, FilterAndSort;
+        FullyQualifiedName: String, FilterAndSort;
+        PrimaryEmailAddr: String, FilterOnly;
+        Balance: f64, FilterAndSort;
+        Active: bool, FilterOnly;
+        Job: bool, FilterOnly;
+        ParentRef: NtRef, FilterOnly;
+    }
+    Department {
+        Name: String, FilterAndSort;
+        FullyQualifiedName: String, FilterAndSort;
+        Active: bool, FilterOnly;
+        ParentRef: NtRef, FilterOnly;
+    }
+    Deposit {
+        TxnDate: NaiveDate, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        DepositToAccountRef: NtRef, FilterOnly;
+        PrivateNote: String, SelectOnly;
+    }
+    Employee {
+        DisplayName: String, FilterAndSort;
+        GivenName: String, FilterAndSort;
+        MiddleName: String, FilterAndSort;
+        FamilyName: String, FilterAndSort;
+        EmployeeNumber: String, FilterOnly;
+        Active: bool, FilterOnly;
+    }
+    Estimate {
+        DocNumber: String, FilterAndSort;
+        TxnDate: NaiveDate, FilterAndSort;
+        ExpirationDate: NaiveDate, FilterAndSort;
+        CustomerRef: NtRef, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        TxnStatus: String, FilterOnly;
+        PrivateNote: String, SelectOnly;
+    }
+    Exchangerate {
+        SourceCurrencyCode: String, FilterOnly;
+        TargetCurrencyCode: String, FilterOnly;
+        AsOfDate: NaiveDate, FilterAndSort;
+    }
+    InventoryAdjustment {
+        DocNumber: String, FilterAndSort;
+        TxnDate: NaiveDate, FilterAndSort;
+        PrivateNote: String, SelectOnly;
+    }
+    Invoice {
+        DocNumber: String, FilterAndSort;
+        TxnDate: NaiveDate, FilterAndSort;
+        DueDate: NaiveDate, FilterAndSort;
+        CustomerRef: NtRef, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        Balance: f64, FilterAndSort;
+        DepartmentRef: NtRef, FilterOnly;
+        PrivateNote: String, SelectOnly;
+    }
+    Item {
+        Name: String, FilterAndSort;
+        Sku: String, FilterOnly;
+        FullyQualifiedName: String, FilterAndSort;
+        Type: ItemType, FilterOnly;
+        Active: bool, FilterOnly;
+        ParentRef: NtRef, FilterOnly;
+        QtyOnHand: i64, FilterAndSort;
+        UnitPrice: f64, FilterAndSort;
+        Description: String, SelectOnly;
+    }
+    JournalEntry {
+        DocNumber: String, FilterAndSort;
+        TxnDate: NaiveDate, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        Adjustment: bool, FilterOnly;
+        PrivateNote: String, SelectOnly;
+    }
+    Payment {
+        TxnDate: NaiveDate, FilterAndSort;
+        CustomerRef: NtRef, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        PaymentRefNum: String, FilterOnly;
+        PrivateNote: String, SelectOnly;
+    }
+    PaymentMethod {
+        Name: String, FilterAndSort;
+        Type: PaymentMethodType, FilterOnly;
+        Active: bool, FilterOnly;
+    }
+    Preferences {}
+    Purchase {
+        DocNumber: String, FilterAndSort;
+        TxnDate: NaiveDate, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        PaymentType: PaymentType, FilterOnly;
+        AccountRef: NtRef, FilterOnly;
+        PrivateNote: String, SelectOnly;
+    }
+    PurchaseOrder {
+        DocNumber: String, FilterAndSort;
+        TxnDate: NaiveDate, FilterAndSort;
+        VendorRef: NtRef, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        POStatus: POStatus, FilterOnly;
+        PrivateNote: String, SelectOnly;
+    }
+    RecurringTransaction {}
+    RefundReceipt {
+        DocNumber: String, FilterAndSort;
+        TxnDate: NaiveDate, FilterAndSort;
+        CustomerRef: NtRef, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        DepositToAccountRef: NtRef, FilterOnly;
+        PrivateNote: String, SelectOnly;
+    }
+    SalesReceipt {
+        DocNumber: String, FilterAndSort;
+        TxnDate: NaiveDate, FilterAndSort;
+        CustomerRef: NtRef, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        DepositToAccountRef: NtRef, FilterOnly;
+        PrivateNote: String, SelectOnly;
+    }
+    TaxAgency {
+        DisplayName: String, FilterAndSort;
+    }
+    TaxCode {
+        Name: String, FilterAndSort;
+        Active: bool, FilterOnly;
+        Taxable: bool, FilterOnly;
+        TaxGroup: bool, FilterOnly;
+    }
+    TaxRate {
+        Name: String, FilterAndSort;
+        Active: bool, FilterOnly;
+        AgencyRef: NtRef, FilterOnly;
+    }
+    Term {
+        Name: String, FilterAndSort;
+        Active: bool, FilterOnly;
+    }
+    TimeActivity {
+        TxnDate: NaiveDate, FilterAndSort;
+        EmployeeRef: NtRef, FilterOnly;
+        VendorRef: NtRef, FilterOnly;
+        CustomerRef: NtRef, FilterOnly;
+        Description: String, SelectOnly;
+    }
+    Transfer {
+        TxnDate: NaiveDate, FilterAndSort;
+        Amount: f64, FilterAndSort;
+        FromAccountRef: NtRef, FilterOnly;
+        ToAccountRef: NtRef, FilterOnly;
+        PrivateNote: String, SelectOnly;
+    }
+    Vendor {
+        DisplayName: String, FilterAndSort;
+        GivenName: String, FilterAndSort;
+        MiddleName: String, FilterAndSort;
+        FamilyName: String, FilterAndSort;
+        CompanyName: String, FilterAndSort;
+        PrimaryEmailAddr: String, FilterOnly;
+        Balance: f64, FilterAndSort;
+        Active: bool, FilterOnly;
+    }
+    VendorCredit {
+        DocNumber: String, FilterAndSort;
+        TxnDate: NaiveDate, FilterAndSort;
+        VendorRef: NtRef, FilterAndSort;
+        TotalAmt: f64, FilterAndSort;
+        Balance: f64, FilterAndSort;
+        PrivateNote: String, SelectOnly;
+    }
+);
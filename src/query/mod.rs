@@ -0,0 +1,514 @@
+//! `QuickBooks` query language builder
+//!
+//! Builds the SQL-like statements accepted by the `query` endpoint, e.g.
+//! `SELECT * FROM Invoice WHERE TxnDate >= '2024-01-01' ORDERBY MetaData.LastUpdatedTime DESC`.
+//! Entity names come from [`QBItem::name()`](crate::QBItem::name) and string literals are
+//! quoted and escaped for you. Columns are taken from the per-entity field catalogs in
+//! [`fields`] (e.g. [`InvoiceField`]), so a misspelled column or a mismatched value type is
+//! a compile error rather than a 400 from the API.
+//!
+//! API reference: <https://developer.intuit.com/app/developer/qbo/docs/learn/explore-the-quickbooks-online-api/data-queries>
+
+use std::{borrow::Cow, fmt::Display, marker::PhantomData};
+
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+
+use crate::{
+    common::NtRef, AttachmentCategory, ItemType, POStatus, PaymentMethodType, PaymentType,
+    QBQueryable,
+};
+
+pub mod fields;
+mod response;
+pub use fields::*;
+pub use response::*;
+
+/// A literal on the right-hand side of a query predicate.
+#[derive(Clone, Debug, PartialEq)]
+pub enum QueryValue {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+    Date(NaiveDate),
+    DateTime(DateTime<Utc>),
+}
+
+impl Display for QueryValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryValue::String(s) => write!(f, "'{}'", escape_literal(s)),
+            QueryValue::Integer(n) => write!(f, "{n}"),
+            QueryValue::Float(n) => write!(f, "{n}"),
+            QueryValue::Bool(b) => write!(f, "{b}"),
+            QueryValue::Date(d) => write!(f, "'{}'", d.format("%Y-%m-%d")),
+            QueryValue::DateTime(dt) => {
+                write!(f, "'{}'", dt.to_rfc3339_opts(SecondsFormat::Secs, false))
+            }
+        }
+    }
+}
+
+/// Escapes backslashes and single quotes so `value` can be placed inside a quoted literal.
+#[must_use]
+pub fn escape_literal(value: &str) -> Cow<'_, str> {
+    if !value.contains(['\'', '\\']) {
+        return Cow::Borrowed(value);
+    }
+    let mut out = String::with_capacity(value.len() + 2);
+    for c in value.chars() {
+        if matches!(c, '\'' | '\\') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    Cow::Owned(out)
+}
+
+macro_rules! impl_query_value_from {
+    ($($ty:ty => $variant:ident $(as $cast:ty)?),+ $(,)?) => {
+        $(
+            impl From<$ty> for QueryValue {
+                fn from(value: $ty) -> Self {
+                    QueryValue::$variant(value $(as $cast)?)
+                }
+            }
+        )+
+    };
+}
+
+impl_query_value_from!(
+    String => String,
+    bool => Bool,
+    i32 => Integer as i64,
+    i64 => Integer,
+    u32 => Integer as i64,
+    f64 => Float,
+    NaiveDate => Date,
+    DateTime<Utc> => DateTime,
+);
+
+impl From<&str> for QueryValue {
+    fn from(value: &str) -> Self {
+        QueryValue::String(value.into())
+    }
+}
+
+/// Reference columns (e.g. `CustomerRef`) compare against the referenced entity's ID.
+///
+/// # Panics
+///
+/// Panics if the reference has no `value`, since there is no ID to compare against.
+impl From<NtRef> for QueryValue {
+    fn from(value: NtRef) -> Self {
+        QueryValue::String(
+            value
+                .value
+                .expect("a reference used in a query needs its ID in `value`"),
+        )
+    }
+}
+
+// Enum columns compare against their serialized name
+macro_rules! impl_query_value_via_serde {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl From<$ty> for QueryValue {
+                fn from(value: $ty) -> Self {
+                    let name = serde_json::to_value(value)
+                        .ok()
+                        .and_then(|v| v.as_str().map(str::to_owned))
+                        .unwrap_or_default();
+                    QueryValue::String(name)
+                }
+            }
+        )+
+    };
+}
+
+impl_query_value_via_serde!(
+    ItemType,
+    AttachmentCategory,
+    PaymentType,
+    PaymentMethodType,
+    POStatus
+);
+
+/// Comparison operators supported by the query language.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Eq,
+    Lt,
+    Gt,
+    Lte,
+    Gte,
+    Like,
+}
+
+impl Operator {
+    /// Returns the operator as it appears in a query.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Operator::Eq => "=",
+            Operator::Lt => "<",
+            Operator::Gt => ">",
+            Operator::Lte => "<=",
+            Operator::Gte => ">=",
+            Operator::Like => "LIKE",
+        }
+    }
+}
+
+/// Sort direction for an `ORDERBY` clause.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    /// Returns the direction as it appears in a query.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Predicate {
+    Compare(Operator, QueryValue),
+    In(Vec<QueryValue>),
+}
+
+/// A single `WHERE` predicate; predicates on a query are joined with `AND`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Condition {
+    field: Cow<'static, str>,
+    predicate: Predicate,
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.predicate {
+            Predicate::Compare(op, value) => write!(f, "{} {} {value}", self.field, op.as_str()),
+            Predicate::In(values) => {
+                write!(f, "{} IN (", self.field)?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Projection {
+    All,
+    Count,
+    Fields(Vec<Cow<'static, str>>),
+}
+
+/// Query builder for a single entity type.
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use quickbooks_types::{
+///     query::{InvoiceField, Query, SortDirection},
+///     Invoice,
+/// };
+///
+/// let query = Query::<Invoice>::new()
+///     .where_gte(InvoiceField::TxnDate, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+///     .where_eq(InvoiceField::CustomerRef, "12")
+///     .order_by(InvoiceField::LastUpdatedTime, SortDirection::Desc)
+///     .start_position(1)
+///     .max_results(1000);
+///
+/// assert_eq!(
+///     query.to_query_string(),
+///     "SELECT * FROM Invoice WHERE TxnDate >= '2024-01-01' AND CustomerRef = '12' \
+///      ORDERBY MetaData.LastUpdatedTime DESC STARTPOSITION 1 MAXRESULTS 1000"
+/// );
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Query<T> {
+    projection: Projection,
+    conditions: Vec<Condition>,
+    order_by: Vec<(Cow<'static, str>, SortDirection)>,
+    start_position: Option<u32>,
+    max_results: Option<u32>,
+    _entity: PhantomData<fn() -> T>,
+}
+
+// Manual impls so `T` itself does not need to be `Clone`/`Default`
+impl<T> Clone for Query<T> {
+    fn clone(&self) -> Self {
+        Self {
+            projection: self.projection.clone(),
+            conditions: self.conditions.clone(),
+            order_by: self.order_by.clone(),
+            start_position: self.start_position,
+            max_results: self.max_results,
+            _entity: PhantomData,
+        }
+    }
+}
+
+impl<T: QBQueryable> Default for Query<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: QBQueryable> Query<T> {
+    /// Creates a `SELECT * FROM <Entity>` query.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            projection: Projection::All,
+            conditions: Vec::new(),
+            order_by: Vec::new(),
+            start_position: None,
+            max_results: None,
+            _entity: PhantomData,
+        }
+    }
+
+    /// Creates a `SELECT COUNT(*) FROM <Entity>` query.
+    #[must_use]
+    pub fn count() -> Self {
+        Self {
+            projection: Projection::Count,
+            ..Self::new()
+        }
+    }
+
+    /// Adds `field` to the projection list, replacing `*`.
+    #[must_use]
+    pub fn select<V, C>(mut self, field: Field<T, V, C>) -> Self {
+        match &mut self.projection {
+            Projection::Fields(fields) => fields.push(field.name().into()),
+            projection => *projection = Projection::Fields(vec![field.name().into()]),
+        }
+        self
+    }
+
+    /// Adds a `field <op> value` predicate.
+    #[must_use]
+    pub fn filter<V, C>(mut self, field: Field<T, V, C>, op: Operator, value: impl Into<V>) -> Self
+    where
+        V: Into<QueryValue>,
+        C: CanFilter,
+    {
+        self.conditions.push(Condition {
+            field: field.name().into(),
+            predicate: Predicate::Compare(op, value.into().into()),
+        });
+        self
+    }
+
+    /// Adds a `field = value` predicate.
+    #[must_use]
+    pub fn where_eq<V, C>(self, field: Field<T, V, C>, value: impl Into<V>) -> Self
+    where
+        V: Into<QueryValue>,
+        C: CanFilter,
+    {
+        self.filter(field, Operator::Eq, value)
+    }
+
+    /// Adds a `field < value` predicate.
+    #[must_use]
+    pub fn where_lt<V, C>(self, field: Field<T, V, C>, value: impl Into<V>) -> Self
+    where
+        V: Into<QueryValue>,
+        C: CanFilter,
+    {
+        self.filter(field, Operator::Lt, value)
+    }
+
+    /// Adds a `field > value` predicate.
+    #[must_use]
+    pub fn where_gt<V, C>(self, field: Field<T, V, C>, value: impl Into<V>) -> Self
+    where
+        V: Into<QueryValue>,
+        C: CanFilter,
+    {
+        self.filter(field, Operator::Gt, value)
+    }
+
+    /// Adds a `field <= value` predicate.
+    #[must_use]
+    pub fn where_lte<V, C>(self, field: Field<T, V, C>, value: impl Into<V>) -> Self
+    where
+        V: Into<QueryValue>,
+        C: CanFilter,
+    {
+        self.filter(field, Operator::Lte, value)
+    }
+
+    /// Adds a `field >= value` predicate.
+    #[must_use]
+    pub fn where_gte<V, C>(self, field: Field<T, V, C>, value: impl Into<V>) -> Self
+    where
+        V: Into<QueryValue>,
+        C: CanFilter,
+    {
+        self.filter(field, Operator::Gte, value)
+    }
+
+    /// Adds a `field LIKE 'pattern'` predicate. Use `%` as the wildcard.
+    #[must_use]
+    pub fn where_like<C: CanFilter>(
+        self,
+        field: Field<T, String, C>,
+        pattern: impl Into<String>,
+    ) -> Self {
+        self.filter(field, Operator::Like, pattern)
+    }
+
+    /// Adds a `field IN (v1, v2, ...)` predicate.
+    #[must_use]
+    pub fn where_in<V, C>(
+        mut self,
+        field: Field<T, V, C>,
+        values: impl IntoIterator<Item = impl Into<V>>,
+    ) -> Self
+    where
+        V: Into<QueryValue>,
+        C: CanFilter,
+    {
+        self.conditions.push(Condition {
+            field: field.name().into(),
+            predicate: Predicate::In(values.into_iter().map(|v| v.into().into()).collect()),
+        });
+        self
+    }
+
+    /// Appends an `ORDERBY` column.
+    #[must_use]
+    pub fn order_by<V, C: CanSort>(
+        mut self,
+        field: Field<T, V, C>,
+        direction: SortDirection,
+    ) -> Self {
+        self.order_by.push((field.name().into(), direction));
+        self
+    }
+
+    /// Sets `STARTPOSITION` (1-based).
+    #[must_use]
+    pub fn start_position(mut self, position: u32) -> Self {
+        self.start_position = Some(position);
+        self
+    }
+
+    /// Sets `MAXRESULTS`. `QuickBooks` caps this at 1000.
+    #[must_use]
+    pub fn max_results(mut self, max: u32) -> Self {
+        self.max_results = Some(max);
+        self
+    }
+
+    /// Renders the query statement.
+    #[must_use]
+    pub fn to_query_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<T: QBQueryable> Display for Query<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SELECT ")?;
+        match &self.projection {
+            Projection::All => f.write_str("*")?,
+            Projection::Count => f.write_str("COUNT(*)")?,
+            Projection::Fields(fields) => f.write_str(&fields.join(", "))?,
+        }
+        write!(f, " FROM {}", T::name())?;
+
+        for (i, condition) in self.conditions.iter().enumerate() {
+            let keyword = if i == 0 { "WHERE" } else { "AND" };
+            write!(f, " {keyword} {condition}")?;
+        }
+
+        for (i, (field, direction)) in self.order_by.iter().enumerate() {
+            let keyword = if i == 0 { " ORDERBY " } else { ", " };
+            write!(f, "{keyword}{field} {}", direction.as_str())?;
+        }
+
+        if let Some(position) = self.start_position {
+            write!(f, " STARTPOSITION {position}")?;
+        }
+        if let Some(max) = self.max_results {
+            write!(f, " MAXRESULTS {max}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Customer, Invoice, Item};
+
+    #[test]
+    fn escapes_string_literals() {
+        let query = Query::<Customer>::new().where_eq(CustomerField::DisplayName, r"Bob's \ Shop");
+        assert_eq!(
+            query.to_query_string(),
+            r"SELECT * FROM Customer WHERE DisplayName = 'Bob\'s \\ Shop'"
+        );
+    }
+
+    #[test]
+    fn count_projection_and_in() {
+        let query = Query::<Item>::count()
+            .where_in(ItemField::Type, [ItemType::Inventory, ItemType::Service])
+            .where_like(ItemField::Name, "Pump%");
+        assert_eq!(
+            query.to_query_string(),
+            "SELECT COUNT(*) FROM Item WHERE Type IN ('Inventory', 'Service') AND Name LIKE 'Pump%'"
+        );
+
+        let query = Query::<Item>::new()
+            .select(ItemField::Id)
+            .select(ItemField::Name)
+            .where_eq(ItemField::Active, true)
+            .order_by(ItemField::Name, SortDirection::Asc);
+        assert_eq!(
+            query.to_query_string(),
+            "SELECT Id, Name FROM Item WHERE Active = true ORDERBY Name ASC"
+        );
+    }
+
+    #[test]
+    fn typed_values() {
+        let query = Query::<Invoice>::new()
+            .where_eq(InvoiceField::CustomerRef, NtRef::from(("John Doe", "12")))
+            .where_gt(InvoiceField::Balance, 0)
+            .where_lt(
+                InvoiceField::DueDate,
+                NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+            );
+        assert_eq!(
+            query.to_query_string(),
+            "SELECT * FROM Invoice WHERE CustomerRef = '12' AND Balance > 0 AND DueDate < '2024-06-01'"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "needs its ID")]
+    fn reference_without_id_panics() {
+        let _ = Query::<Invoice>::new().where_eq(InvoiceField::CustomerRef, NtRef::default());
+    }
+}
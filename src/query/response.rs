@@ -0,0 +1,305 @@
+//! Response envelopes for the `query` endpoint and pagination helpers.
+
+use std::{fmt, marker::PhantomData};
+
+use chrono::{DateTime, FixedOffset};
+use serde::{
+    de::{IgnoredAny, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_with::skip_serializing_none;
+
+use super::Query;
+use crate::{Fault, QBItem, QBQueryable, QBTypeError};
+
+/// Top-level envelope returned by the API.
+///
+/// ```
+/// use quickbooks_types::{query::IntuitResponse, Invoice};
+///
+/// let json = r#"{
+///   "QueryResponse": {
+///     "Invoice": [{ "Id": "130" }],
+///     "startPosition": 1,
+///     "maxResults": 1,
+///     "totalCount": 1
+///   },
+///   "time": "2024-05-01T10:00:00.000-07:00"
+/// }"#;
+///
+/// let response: IntuitResponse<Invoice> = serde_json::from_str(json).unwrap();
+/// let page = response.query_response.unwrap();
+/// assert_eq!(page.items[0].id.as_deref(), Some("130"));
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(bound(deserialize = "T: QBItem", serialize = "T: QBItem"))]
+pub struct IntuitResponse<T> {
+    /// Result of a query request
+    #[serde(rename = "QueryResponse", default)]
+    pub query_response: Option<QueryResponse<T>>,
+    /// Error payload, present instead of `query_response` when the request failed
+    #[serde(rename = "Fault", alias = "fault", default)]
+    pub fault: Option<Fault>,
+    /// Server time the response was produced
+    #[serde(default)]
+    pub time: Option<DateTime<FixedOffset>>,
+}
+
+impl<T> IntuitResponse<T> {
+    /// Returns the query result, or the server's fault as an error.
+    ///
+    /// A response with neither a fault nor a `QueryResponse` yields an empty page.
+    pub fn into_result(self) -> Result<QueryResponse<T>, QBTypeError> {
+        match self.fault {
+            Some(fault) => Err(fault.into()),
+            None => Ok(self.query_response.unwrap_or_default()),
+        }
+    }
+}
+
+/// Body of the `QueryResponse` object.
+///
+/// The entities are stored under a key named after the entity (e.g. `"Invoice"`), which is
+/// resolved through [`QBItem::name()`]. A `COUNT(*)` query only fills `total_count`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryResponse<T> {
+    /// Entities returned in this page
+    pub items: Vec<T>,
+    /// 1-based position of the first entity in this page
+    pub start_position: Option<u32>,
+    /// Maximum number of entities the page could hold
+    pub max_results: Option<u32>,
+    /// Total matching entities (or the count for `COUNT(*)` queries)
+    pub total_count: Option<u32>,
+}
+
+impl<T> Default for QueryResponse<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            start_position: None,
+            max_results: None,
+            total_count: None,
+        }
+    }
+}
+
+impl<'de, T: QBItem> Deserialize<'de> for QueryResponse<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct QueryResponseVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: QBItem> Visitor<'de> for QueryResponseVisitor<T> {
+            type Value = QueryResponse<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a QueryResponse object containing {}", T::name())
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut out = QueryResponse::default();
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "startPosition" => out.start_position = map.next_value()?,
+                        "maxResults" => out.max_results = map.next_value()?,
+                        "totalCount" => out.total_count = map.next_value()?,
+                        name if name == T::name() => out.items = map.next_value()?,
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(out)
+            }
+        }
+
+        deserializer.deserialize_map(QueryResponseVisitor(PhantomData))
+    }
+}
+
+impl<T: QBItem> Serialize for QueryResponse<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        if !self.items.is_empty() {
+            map.serialize_entry(T::name(), &self.items)?;
+        }
+        if let Some(start) = self.start_position {
+            map.serialize_entry("startPosition", &start)?;
+        }
+        if let Some(max) = self.max_results {
+            map.serialize_entry("maxResults", &max)?;
+        }
+        if let Some(total) = self.total_count {
+            map.serialize_entry("totalCount", &total)?;
+        }
+        map.end()
+    }
+}
+
+/// Walks a query page by page using `STARTPOSITION` / `MAXRESULTS`.
+///
+/// The paginator is stateless: hand it the last page you received and it returns the
+/// query for the next one, or `None` once the result set is exhausted.
+///
+/// ```
+/// use quickbooks_types::{
+///     query::{Paginator, QueryResponse},
+///     Customer, QBQueryable,
+/// };
+///
+/// let pages = Paginator::new(Customer::query(), 100);
+/// assert_eq!(
+///     pages.first_page().to_query_string(),
+///     "SELECT * FROM Customer STARTPOSITION 1 MAXRESULTS 100"
+/// );
+///
+/// let last = QueryResponse {
+///     items: vec![Customer::default(); 100],
+///     start_position: Some(1),
+///     max_results: Some(100),
+///     total_count: Some(250),
+/// };
+/// let next = pages.next_page(&last).unwrap();
+/// assert_eq!(
+///     next.to_query_string(),
+///     "SELECT * FROM Customer STARTPOSITION 101 MAXRESULTS 100"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Paginator<T> {
+    query: Query<T>,
+    page_size: u32,
+}
+
+impl<T: QBQueryable> Paginator<T> {
+    /// Maximum page size accepted by the API.
+    pub const MAX_PAGE_SIZE: u32 = 1000;
+
+    /// Creates a paginator over `query`; `page_size` is clamped to `1..=1000`.
+    /// Any `STARTPOSITION` / `MAXRESULTS` already set on `query` is overridden.
+    #[must_use]
+    pub fn new(query: Query<T>, page_size: u32) -> Self {
+        Self {
+            query,
+            page_size: page_size.clamp(1, Self::MAX_PAGE_SIZE),
+        }
+    }
+
+    /// Number of entities requested per page.
+    #[must_use]
+    pub fn page_size(&self) -> u32 {
+        self.page_size
+    }
+
+    /// Query for the page starting at `start_position` (1-based).
+    #[must_use]
+    pub fn page(&self, start_position: u32) -> Query<T> {
+        self.query
+            .clone()
+            .start_position(start_position)
+            .max_results(self.page_size)
+    }
+
+    /// Query for the first page.
+    #[must_use]
+    pub fn first_page(&self) -> Query<T> {
+        self.page(1)
+    }
+
+    /// Query for the page following `last`, or `None` if `last` was the final page.
+    ///
+    /// A page is final when it holds fewer entities than the page size, or when the
+    /// server reported a `totalCount` that the next start position would exceed.
+    #[must_use]
+    pub fn next_page(&self, last: &QueryResponse<T>) -> Option<Query<T>> {
+        let returned = u32::try_from(last.items.len()).unwrap_or(u32::MAX);
+        if returned == 0 || returned < self.page_size {
+            return None;
+        }
+        let next_start = last.start_position.unwrap_or(1).saturating_add(returned);
+        // Some endpoints report the page size as `totalCount`, so only trust it when it
+        // is larger than what was returned.
+        if let Some(total) = last.total_count {
+            if total > returned && next_start > total {
+                return None;
+            }
+        }
+        Some(self.page(next_start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Customer, Invoice};
+
+    #[test]
+    fn deserialize_query_envelope() {
+        let json = r#"{
+          "QueryResponse": {
+            "Invoice": [
+              { "Id": "130", "SyncToken": "0", "TotalAmt": 20.0 },
+              { "Id": "131", "SyncToken": "2", "TotalAmt": 35.5 }
+            ],
+            "startPosition": 1,
+            "maxResults": 2,
+            "totalCount": 250
+          },
+          "time": "2024-05-01T10:00:00.000-07:00"
+        }"#;
+        let response: IntuitResponse<Invoice> = serde_json::from_str(json).unwrap();
+        let page = response.query_response.unwrap();
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[1].total_amt, Some(35.5));
+        assert_eq!(page.total_count, Some(250));
+        assert!(response.time.is_some());
+
+        // COUNT(*) and empty result sets carry no entity key
+        let count: IntuitResponse<Invoice> =
+            serde_json::from_str(r#"{"QueryResponse": {"totalCount": 42}}"#).unwrap();
+        let count = count.query_response.unwrap();
+        assert!(count.items.is_empty());
+        assert_eq!(count.total_count, Some(42));
+    }
+
+    #[test]
+    fn fault_envelope_into_result() {
+        let json = r#"{
+          "Fault": {
+            "Error": [{ "Message": "Stale Object Error", "code": "5010" }],
+            "type": "ValidationFault"
+          },
+          "time": "2024-05-01T10:00:00.000-07:00"
+        }"#;
+        let response: IntuitResponse<Invoice> = serde_json::from_str(json).unwrap();
+        let err = response.into_result().unwrap_err();
+        assert_eq!(err.fault_kind(), Some(crate::QBFaultKind::StaleObject));
+    }
+
+    #[test]
+    fn paginator_stops_on_short_or_final_page() {
+        let pages = Paginator::new(Customer::query(), 2);
+        let page = |start, len, total| QueryResponse {
+            items: vec![Customer::default(); len],
+            start_position: Some(start),
+            max_results: Some(2),
+            total_count: total,
+        };
+
+        assert_eq!(pages.next_page(&page(1, 2, None)).unwrap(), pages.page(3));
+        assert!(pages.next_page(&page(3, 1, None)).is_none());
+        assert!(pages.next_page(&page(3, 2, Some(4))).is_none());
+        // `totalCount` equal to the page length is not a total
+        assert!(pages.next_page(&page(3, 2, Some(2))).is_some());
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! API reference: <https://developer.intuit.com/app/developer/qbo/docs/api/accounting/report-entities/accountlistdetail>
 
+use chrono::NaiveDate;
+
 mod models;
 pub mod params;
 pub mod types;
@@ -40,6 +42,41 @@ impl Report {
             .map(|cols| cols.iter().map(|c| c.col_title.as_str()))
     }
 
+    /// Data rows at any depth of nested sections, in report order.
+    ///
+    /// Section headers that carry values of their own (e.g. a parent account with sub-accounts)
+    /// are included before the section's rows; summary rows are not.
+    #[must_use]
+    pub fn leaf_rows(&self) -> Vec<&[ColData]> {
+        fn collect<'a>(rows: &'a Rows, out: &mut Vec<&'a [ColData]>) {
+            for row in rows.row.iter().flatten() {
+                match &row.content {
+                    RowContent::Coldata { col_data } => out.push(col_data),
+                    RowContent::HeaderRowsSummary { header, rows, .. } => {
+                        let header = header.as_ref().and_then(|h| h.col_data.as_deref());
+                        if let Some(header) = header.filter(|cols| {
+                            cols.first().is_some_and(|c| c.id.is_some())
+                                && cols[1..]
+                                    .iter()
+                                    .any(|c| c.value.as_deref().is_some_and(|v| !v.is_empty()))
+                        }) {
+                            out.push(header);
+                        }
+                        if let Some(rows) = rows {
+                            collect(rows, out);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+        if let Some(rows) = &self.rows {
+            collect(rows, &mut out);
+        }
+        out
+    }
+
     #[must_use]
     pub fn row_data(&self) -> Option<impl Iterator<Item = &[ColData]>> {
         self.rows.as_ref()?.row.as_ref().map(|rows| {
@@ -54,6 +91,22 @@ impl Report {
     }
 }
 
+impl Column {
+    /// Date range of the column from its `StartDate` / `EndDate` metadata, set on the period
+    /// columns of reports summarized by date (e.g. `summarize_column_by=Month`).
+    #[must_use]
+    pub fn period(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let date = |name: &str| {
+            self.meta_data
+                .iter()
+                .flatten()
+                .find(|m| m.name.as_deref() == Some(name))
+                .and_then(|m| m.value.as_deref()?.parse().ok())
+        };
+        Some((date("StartDate")?, date("EndDate")?))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
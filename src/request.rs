@@ -0,0 +1,412 @@
+//! Sans-IO request descriptors
+//!
+//! [`QBRequest`] describes an API call — method, realm-relative path, query parameters and
+//! JSON body — without performing it. The capability traits build these for each entity
+//! (e.g. [`QBCreatable::create_request()`](crate::QBCreatable::create_request)), so an HTTP
+//! client only has to prefix the base URL, attach auth and send it.
+//!
+//! ```
+//! use quickbooks_types::{request::Method, Invoice, QBVoidable};
+//!
+//! let invoice = Invoice {
+//!     id: Some("130".into()),
+//!     sync_token: Some("2".into()),
+//!     ..Default::default()
+//! };
+//!
+//! let request = invoice.void_request("1234").unwrap();
+//! assert_eq!(request.method, Method::Post);
+//! assert_eq!(
+//!     request.path_and_query(),
+//!     "/v3/company/1234/invoice?operation=void&minorversion=75"
+//! );
+//! assert_eq!(request.body.unwrap()["SyncToken"], "2");
+//! ```
+
+use std::fmt::Write;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::{query::Query, QBItem, QBTypeError, ValidationIssue};
+
+/// Minor version sent with every request unless overridden with [`QBRequest::minor_version()`].
+pub const DEFAULT_MINOR_VERSION: u32 = 75;
+
+/// HTTP method of a [`QBRequest`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+impl Method {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        }
+    }
+}
+
+/// A single API call, ready to be sent by any HTTP client.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QBRequest {
+    pub method: Method,
+    /// Path relative to the API host, e.g. `/v3/company/1234/invoice`
+    pub path: String,
+    /// Query parameters in order, including `minorversion`
+    pub query: Vec<(&'static str, String)>,
+    /// JSON body, for requests that send one
+    pub body: Option<Value>,
+    /// Expected `Content-Type` of the request body
+    pub content_type: Option<&'static str>,
+    /// Expected `Accept` header
+    pub accept: &'static str,
+}
+
+impl QBRequest {
+    /// Creates a request for `/v3/company/{realm_id}/{resource}` with no body.
+    #[must_use]
+    pub fn new(method: Method, realm_id: &str, resource: &str) -> Self {
+        Self {
+            method,
+            path: format!("/v3/company/{realm_id}/{resource}"),
+            query: vec![("minorversion", DEFAULT_MINOR_VERSION.to_string())],
+            body: None,
+            content_type: None,
+            accept: "application/json",
+        }
+    }
+
+    /// Adds a query parameter before `minorversion`.
+    #[must_use]
+    pub fn param(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        let index = self
+            .query
+            .iter()
+            .position(|(n, _)| *n == "minorversion")
+            .unwrap_or(self.query.len());
+        self.query.insert(index, (name, value.into()));
+        self
+    }
+
+    /// Sets the JSON body.
+    #[must_use]
+    pub fn json(mut self, body: Value) -> Self {
+        self.body = Some(body);
+        self.content_type = Some("application/json");
+        self
+    }
+
+    /// Overrides the `minorversion` query parameter.
+    #[must_use]
+    pub fn minor_version(mut self, version: u32) -> Self {
+        match self.query.iter_mut().find(|(n, _)| *n == "minorversion") {
+            Some((_, v)) => *v = version.to_string(),
+            None => self.query.push(("minorversion", version.to_string())),
+        }
+        self
+    }
+
+    /// Value of a query parameter.
+    #[must_use]
+    pub fn query_param(&self, name: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Path with the percent-encoded query string appended.
+    #[must_use]
+    pub fn path_and_query(&self) -> String {
+        let mut out = self.path.clone();
+        for (i, (name, value)) in self.query.iter().enumerate() {
+            out.push(if i == 0 { '?' } else { '&' });
+            out.push_str(name);
+            out.push('=');
+            out.push_str(&encode_query_value(value));
+        }
+        out
+    }
+
+    /// `GET` for the entity of type `T` with the given ID.
+    #[must_use]
+    pub fn read<T: QBItem>(realm_id: &str, id: &str) -> Self {
+        // Preferences is a per-company singleton read without an ID
+        if T::qb_id() == "preferences" {
+            return Self::new(Method::Get, realm_id, T::qb_id());
+        }
+        Self::new(Method::Get, realm_id, &format!("{}/{id}", T::qb_id()))
+    }
+
+    /// `GET /query` running the given query statement.
+    #[must_use]
+    pub fn query<T: QBItem>(realm_id: &str, query: &Query<T>) -> Self {
+        Self::new(Method::Get, realm_id, "query").param("query", query.to_query_string())
+    }
+
+    /// `POST` of `entity` to its endpoint, with optional `operation` / `include` parameters.
+    pub(crate) fn post_entity<T: QBItem>(
+        realm_id: &str,
+        entity: &impl Serialize,
+        params: &[(&'static str, &str)],
+    ) -> Result<Self, QBTypeError> {
+        let body = to_body::<T>(entity)?;
+        let mut request = Self::new(Method::Post, realm_id, T::qb_id());
+        for (name, value) in params {
+            request = request.param(name, *value);
+        }
+        Ok(request.json(body))
+    }
+
+    /// `POST` of just `Id` / `SyncToken` with the given `operation`, as used by delete and void.
+    pub(crate) fn post_reference<T: QBItem>(
+        realm_id: &str,
+        entity: &T,
+        params: &[(&'static str, &str)],
+    ) -> Result<Self, QBTypeError> {
+        let body = json!({ "Id": entity.id(), "SyncToken": entity.sync_token() });
+        Self::post_entity::<T>(realm_id, &body, params)
+    }
+
+    /// Void through a sparse update (`operation=update&include=void`), used by payments,
+    /// sales receipts and purchases instead of `operation=void`.
+    pub(crate) fn void_via_update<T: QBItem>(
+        realm_id: &str,
+        entity: &T,
+    ) -> Result<Self, QBTypeError> {
+        precondition::<T>(entity.has_read(), "void")?;
+        let body = json!({ "Id": entity.id(), "SyncToken": entity.sync_token(), "sparse": true });
+        Self::post_entity::<T>(
+            realm_id,
+            &body,
+            &[("operation", "update"), ("include", "void")],
+        )
+    }
+}
+
+impl std::fmt::Display for QBRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.method.as_str(), self.path_and_query())
+    }
+}
+
+pub(crate) fn precondition<T: QBItem>(
+    ok: bool,
+    operation: &'static str,
+) -> Result<(), QBTypeError> {
+    if ok {
+        Ok(())
+    } else {
+        Err(QBTypeError::PreconditionFailed {
+            entity: T::name(),
+            operation,
+        })
+    }
+}
+
+pub(crate) fn validated<T: QBItem>(
+    result: Result<(), Vec<ValidationIssue>>,
+    operation: &'static str,
+) -> Result<(), QBTypeError> {
+    result.map_err(|issues| QBTypeError::ValidationFailed {
+        entity: T::name(),
+        operation,
+        issues,
+    })
+}
+
+/// ID of an entity that passed a `has_read` / `can_read` check.
+pub(crate) fn entity_id<T: QBItem>(entity: &T) -> &str {
+    entity.id().map_or("", String::as_str)
+}
+
+/// Fields never copied into a sparse diff; `Id` and `SyncToken` come from the original.
+const SPARSE_SKIP: &[&str] = &["Id", "SyncToken", "sparse", "MetaData"];
+
+pub(crate) fn sparse_diff<T: QBItem>(original: &T, modified: &T) -> Result<Value, QBTypeError> {
+    precondition::<T>(original.has_read(), "sparse update")?;
+    precondition::<T>(
+        modified.id().is_none() || modified.id() == original.id(),
+        "sparse update",
+    )?;
+    let before = to_body::<T>(original)?;
+    let after = to_body::<T>(modified)?;
+    let mut body = serde_json::Map::new();
+    body.insert("Id".into(), json!(original.id()));
+    body.insert("SyncToken".into(), json!(original.sync_token()));
+    body.insert("sparse".into(), true.into());
+    if let Value::Object(fields) = &after {
+        for (key, value) in fields {
+            if SPARSE_SKIP.contains(&key.as_str()) || before.get(key) == Some(value) {
+                continue;
+            }
+            body.insert(key.clone(), value.clone());
+        }
+    }
+    // Fields cleared to `None` are skipped when serializing, so send them as explicit nulls
+    if let Value::Object(fields) = &before {
+        for key in fields.keys() {
+            if !SPARSE_SKIP.contains(&key.as_str()) && after.get(key).is_none() {
+                body.insert(key.clone(), Value::Null);
+            }
+        }
+    }
+    Ok(Value::Object(body))
+}
+
+fn to_body<T: QBItem>(entity: &impl Serialize) -> Result<Value, QBTypeError> {
+    serde_json::to_value(entity).map_err(|e| QBTypeError::InvalidPayload {
+        entity: T::name(),
+        message: e.to_string(),
+    })
+}
+
+fn encode_query_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char);
+            }
+            _ => {
+                let _ = write!(out, "%{byte:02X}");
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Customer, Invoice, Payment, Preferences, QBCreatable, QBDeletable, QBFullUpdatable,
+        QBPDFable, QBQueryable, QBReadable, QBSendable, QBSparseUpdateable, QBVoidable,
+    };
+
+    fn read_invoice() -> Invoice {
+        Invoice {
+            id: Some("130".into()),
+            sync_token: Some("2".into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn entity_requests() {
+        let customer = Customer {
+            display_name: Some("John Doe".into()),
+            ..Default::default()
+        };
+        let create = customer.clone().create_request("1234").unwrap();
+        assert_eq!(
+            create.to_string(),
+            "POST /v3/company/1234/customer?minorversion=75"
+        );
+        assert_eq!(create.body.unwrap()["DisplayName"], "John Doe");
+        assert!(Customer::default().create_request("1234").is_err());
+
+        let invoice = read_invoice();
+        assert_eq!(
+            invoice.read_request("1234").unwrap().to_string(),
+            "GET /v3/company/1234/invoice/130?minorversion=75"
+        );
+        assert_eq!(
+            invoice.delete_request("1234").unwrap().path_and_query(),
+            "/v3/company/1234/invoice?operation=delete&minorversion=75"
+        );
+        assert_eq!(
+            invoice
+                .send_request("1234", Some("a+b@example.com"))
+                .unwrap()
+                .path_and_query(),
+            "/v3/company/1234/invoice/130/send?sendTo=a%2Bb%40example.com&minorversion=75"
+        );
+        let pdf = invoice.pdf_request("1234").unwrap();
+        assert_eq!(pdf.path, "/v3/company/1234/invoice/130/pdf");
+        assert_eq!(pdf.accept, "application/pdf");
+
+        // Payments are voided through an update
+        let payment = Payment {
+            id: Some("7".into()),
+            sync_token: Some("0".into()),
+            ..Default::default()
+        };
+        let void = payment.void_request("1234").unwrap();
+        assert_eq!(void.query_param("operation"), Some("update"));
+        assert_eq!(void.query_param("include"), Some("void"));
+        assert_eq!(void.body.unwrap()["sparse"], true);
+
+        let sparse = Customer {
+            id: Some("58".into()),
+            sync_token: Some("1".into()),
+            ..customer
+        };
+        let body = sparse.sparse_update_request("1234").unwrap().body.unwrap();
+        assert_eq!(body["sparse"], true);
+        assert!(Invoice::default().full_update_request("1234").is_err());
+
+        assert_eq!(
+            QBRequest::read::<Preferences>("1234", "")
+                .minor_version(70)
+                .path_and_query(),
+            "/v3/company/1234/preferences?minorversion=70"
+        );
+    }
+
+    #[test]
+    fn sparse_diff_only_sends_changes() {
+        let original = Invoice {
+            id: Some("130".into()),
+            sync_token: Some("3".into()),
+            doc_number: Some("1001".into()),
+            private_note: Some("old note".into()),
+            total_amt: Some(20.0),
+            ..Default::default()
+        };
+        let mut modified = original.clone();
+        modified.private_note = Some("new note".into());
+        modified.due_date = chrono::NaiveDate::from_ymd_opt(2024, 6, 1);
+        // Cleared fields are sent as null
+        modified.doc_number = None;
+
+        let body = modified.sparse_update_diff(&original).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "Id": "130",
+                "SyncToken": "3",
+                "sparse": true,
+                "PrivateNote": "new note",
+                "DueDate": "2024-06-01",
+                "DocNumber": null
+            })
+        );
+
+        assert!(modified.sparse_update_diff(&Invoice::default()).is_err());
+        let other = Invoice {
+            id: Some("131".into()),
+            ..modified.clone()
+        };
+        assert!(other.sparse_update_diff(&original).is_err());
+
+        // The request itself also needs the modified entity to pass `can_sparse_update()`
+        assert!(matches!(
+            modified.sparse_update_request_from(&original, "1234"),
+            Err(QBTypeError::PreconditionFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn query_request_is_encoded() {
+        let request = QBRequest::query("1234", &Customer::query().max_results(10));
+        assert_eq!(
+            request.path_and_query(),
+            "/v3/company/1234/query?query=SELECT%20%2A%20FROM%20Customer%20MAXRESULTS%2010&minorversion=75"
+        );
+    }
+}
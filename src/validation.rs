@@ -0,0 +1,306 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::QBItem;
+
+/// `ValidationRule` Enum
+///
+/// The kind of local precondition a [`ValidationIssue`] failed.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationRule {
+    /// A required field is missing
+    Required,
+    /// At least one of several fields is required
+    RequiredOneOf,
+    /// A field is required because of the value of another field (e.g. an item's `Type`)
+    ConditionallyRequired,
+    /// The entity must have been read from `QuickBooks` (`Id` and `SyncToken`)
+    MustBeRead,
+    /// A field has a value that `QuickBooks` will reject
+    InvalidValue,
+    /// A field uses a feature the company's plan or preferences do not provide
+    FeatureUnavailable,
+}
+
+impl ValidationRule {
+    /// Stable identifier of the rule, e.g. `"required"`.
+    #[must_use]
+    pub fn id(&self) -> &'static str {
+        match self {
+            ValidationRule::Required => "required",
+            ValidationRule::RequiredOneOf => "required_one_of",
+            ValidationRule::ConditionallyRequired => "conditionally_required",
+            ValidationRule::MustBeRead => "must_be_read",
+            ValidationRule::InvalidValue => "invalid_value",
+            ValidationRule::FeatureUnavailable => "feature_unavailable",
+        }
+    }
+}
+
+impl Display for ValidationRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+/// `ValidationIssue`
+///
+/// A single reason an entity fails `validate_create()` / `validate_update()`.
+///
+/// `path` uses the API field names, with list indices in brackets, e.g.
+/// `Line[2].Amount`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct ValidationIssue {
+    /// Path to the offending field
+    pub path: String,
+    /// Rule that failed
+    pub rule: ValidationRule,
+    /// Human readable explanation
+    pub message: String,
+}
+
+impl ValidationIssue {
+    #[must_use]
+    pub fn new(path: impl Into<String>, rule: ValidationRule, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            rule,
+            message: message.into(),
+        }
+    }
+
+    /// Prepends `prefix` to the path; indices (`[2]`) are joined without a dot.
+    #[must_use]
+    pub fn prefixed(mut self, prefix: &str) -> Self {
+        self.path = match (prefix.is_empty(), self.path.is_empty()) {
+            (true, _) => self.path,
+            (false, true) => prefix.to_owned(),
+            (false, false) if self.path.starts_with('[') => format!("{prefix}{}", self.path),
+            (false, false) => format!("{prefix}.{}", self.path),
+        };
+        self
+    }
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} ({})", self.path, self.message, self.rule)
+    }
+}
+
+/// Collects [`ValidationIssue`]s for the `validate_*` implementations.
+#[derive(Debug, Default)]
+pub(crate) struct Validator {
+    issues: Vec<ValidationIssue>,
+}
+
+impl Validator {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn push(&mut self, issue: ValidationIssue) -> &mut Self {
+        self.issues.push(issue);
+        self
+    }
+
+    /// Records an issue at `path` unless `ok`.
+    pub(crate) fn check(
+        &mut self,
+        ok: bool,
+        path: &str,
+        rule: ValidationRule,
+        message: impl Into<String>,
+    ) -> &mut Self {
+        if !ok {
+            self.push(ValidationIssue::new(path, rule, message));
+        }
+        self
+    }
+
+    pub(crate) fn require<T>(&mut self, path: &str, value: &Option<T>) -> &mut Self {
+        self.check(
+            value.is_some(),
+            path,
+            ValidationRule::Required,
+            format!("{path} is required"),
+        )
+    }
+
+    /// Requires at least one of `paths`; the issue is reported at the first path.
+    pub(crate) fn require_one_of(&mut self, paths: &[&str], present: bool) -> &mut Self {
+        self.check(
+            present,
+            paths.first().copied().unwrap_or_default(),
+            ValidationRule::RequiredOneOf,
+            format!("one of {} is required", paths.join(", ")),
+        )
+    }
+
+    /// Requires `value` because of `reason`, e.g. `"when Type is Inventory"`.
+    pub(crate) fn require_because<T>(
+        &mut self,
+        path: &str,
+        value: &Option<T>,
+        reason: &str,
+    ) -> &mut Self {
+        self.check(
+            value.is_some(),
+            path,
+            ValidationRule::ConditionallyRequired,
+            format!("{path} is required {reason}"),
+        )
+    }
+
+    /// Requires `Id` and `SyncToken`, as for any update.
+    pub(crate) fn require_read<T: QBItem>(&mut self, entity: &T) -> &mut Self {
+        for (path, present) in [("Id", entity.id()), ("SyncToken", entity.sync_token())] {
+            self.check(
+                present.is_some(),
+                path,
+                ValidationRule::MustBeRead,
+                format!("{path} is required; read the {} first", T::name()),
+            );
+        }
+        self
+    }
+
+    /// Merges the result of validating a nested value found at `prefix`.
+    pub(crate) fn nested(
+        &mut self,
+        prefix: &str,
+        result: Result<(), Vec<ValidationIssue>>,
+    ) -> &mut Self {
+        if let Err(issues) = result {
+            self.issues
+                .extend(issues.into_iter().map(|i| i.prefixed(prefix)));
+        }
+        self
+    }
+
+    pub(crate) fn finish(&mut self) -> Result<(), Vec<ValidationIssue>> {
+        if self.issues.is_empty() {
+            Ok(())
+        } else {
+            Err(std::mem::take(&mut self.issues))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Invoice, Item, ItemGroupDetail, ItemGroupLine, ItemType, Line, LineDetail, NtRef,
+        QBCreatable, QBFullUpdatable, QBTypeError, SalesItemLineDetail,
+    };
+
+    fn sales_line(item_ref: Option<NtRef>, amount: Option<f64>) -> Line {
+        Line {
+            amount,
+            line_detail: LineDetail::SalesItemLineDetail(SalesItemLineDetail {
+                item_ref,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn issues_carry_nested_paths() {
+        let invoice = Invoice {
+            customer_ref: Some(("Acme", "7").into()),
+            line: Some(vec![
+                sales_line(Some(("Widget", "1").into()), Some(10.0)),
+                Line::default(),
+                sales_line(None, None),
+            ]),
+            ..Default::default()
+        };
+        let issues = invoice.validate_create().unwrap_err();
+        assert_eq!(
+            issues,
+            vec![
+                ValidationIssue::new(
+                    "Line[1].Amount",
+                    ValidationRule::Required,
+                    "Amount is required"
+                ),
+                ValidationIssue::new(
+                    "Line[2].Amount",
+                    ValidationRule::Required,
+                    "Amount is required"
+                ),
+            ]
+        );
+
+        let update = invoice.validate_update().unwrap_err();
+        assert_eq!(update[0].path, "Id");
+        assert_eq!(update[0].rule, ValidationRule::MustBeRead);
+        assert_eq!(update.len(), 4);
+
+        let err = invoice.create_request("1234").unwrap_err();
+        assert!(
+            matches!(err, QBTypeError::ValidationFailed { ref issues, .. } if issues.len() == 2)
+        );
+        assert!(err.to_string().contains("Line[2].Amount"));
+    }
+
+    #[test]
+    fn conditional_requirements_name_the_condition() {
+        let item = Item {
+            name: Some("Widget".into()),
+            item_type: Some(ItemType::Service),
+            ..Default::default()
+        };
+        let issues = item.validate_create().unwrap_err();
+        let paths: Vec<_> = issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, ["ExpenseAccountRef", "IncomeAccountRef"]);
+        assert_eq!(issues[1].rule, ValidationRule::ConditionallyRequired);
+        assert_eq!(
+            issues[1].to_string(),
+            "IncomeAccountRef: IncomeAccountRef is required when Type is Service (conditionally_required)"
+        );
+        assert!(!item.can_create());
+    }
+
+    #[test]
+    fn item_rules_follow_item_type() {
+        let category = Item {
+            name: Some("Garden".into()),
+            item_type: Some(ItemType::Category),
+            ..Default::default()
+        };
+        assert!(category.can_create());
+        let with_account = Item {
+            income_account_ref: Some("79".into()),
+            ..category
+        };
+        assert_eq!(
+            with_account.validate_create().unwrap_err()[0].path,
+            "IncomeAccountRef"
+        );
+
+        let mut bundle = Item {
+            name: Some("Fountain Kit".into()),
+            item_type: Some(ItemType::Group),
+            ..Default::default()
+        };
+        assert_eq!(
+            bundle.validate_create().unwrap_err()[0].rule,
+            ValidationRule::ConditionallyRequired
+        );
+        bundle.item_group_detail = Some(ItemGroupDetail {
+            item_group_line: Some(vec![ItemGroupLine {
+                item_ref: Some("5".into()),
+                qty: Some(0.0),
+            }]),
+        });
+        assert_eq!(
+            bundle.validate_create().unwrap_err()[0].path,
+            "ItemGroupDetail.ItemGroupLine[0].Qty"
+        );
+    }
+}
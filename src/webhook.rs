@@ -0,0 +1,338 @@
+//! `QuickBooks` webhook notifications
+//!
+//! Payload types for the webhook notifications `QuickBooks` Online posts when entities change,
+//! in both the legacy `eventNotifications` format and the `CloudEvents` format, and
+//! [`verify_signature`] for checking the `intuit-signature` header.
+//!
+//! ```
+//! use quickbooks_types::{webhook::{WebhookOperation, WebhookPayload}, QBEntityKind};
+//!
+//! let body = r#"{
+//!   "eventNotifications": [{
+//!     "realmId": "1185883450",
+//!     "dataChangeEvent": {
+//!       "entities": [
+//!         { "name": "Customer", "id": "1", "operation": "Create", "lastUpdated": "2015-10-05T14:42:19-0700" }
+//!       ]
+//!     }
+//!   }]
+//! }"#;
+//!
+//! let payload: WebhookPayload = serde_json::from_str(body).unwrap();
+//! let events = payload.events();
+//! assert_eq!(events[0].realm_id, "1185883450");
+//! assert_eq!(events[0].kind, Some(QBEntityKind::Customer));
+//! assert_eq!(events[0].operation, WebhookOperation::Create);
+//! ```
+//!
+//! API reference: <https://developer.intuit.com/app/developer/qbo/docs/develop/webhooks>
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, FixedOffset};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use sha2::Sha256;
+
+use crate::QBEntityKind;
+
+/// Name of the HTTP header carrying the payload signature.
+pub const SIGNATURE_HEADER: &str = "intuit-signature";
+
+/// Checks a webhook `body` against the base64 HMAC-SHA256 signature in the
+/// `intuit-signature` header, keyed with the app's webhook verifier token.
+///
+/// `body` must be the raw request bytes, before any JSON parsing. The comparison is
+/// constant-time.
+///
+/// ```
+/// use quickbooks_types::webhook::verify_signature;
+///
+/// let body = br#"{"eventNotifications":[]}"#;
+/// let signature = "PoTD6NkQ7ZMymzly6D5JRrVrHxAE3mCtl20M12uy6iM=";
+/// assert!(verify_signature(body, signature, "verifier-token"));
+/// assert!(!verify_signature(body, signature, "another-token"));
+/// ```
+#[must_use]
+pub fn verify_signature(
+    body: impl AsRef<[u8]>,
+    intuit_signature_header: &str,
+    verifier_token: &str,
+) -> bool {
+    let Ok(signature) = STANDARD.decode(intuit_signature_header.trim()) else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(verifier_token.as_bytes()) else {
+        return false;
+    };
+    mac.update(body.as_ref());
+    mac.verify_slice(&signature).is_ok()
+}
+
+/// Body of a webhook request in either supported format.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum WebhookPayload {
+    /// `{"eventNotifications": [...]}`
+    Legacy(WebhookNotifications),
+    /// `[{"specversion": "1.0", "type": "qbo.customer.created.v1", ...}]`
+    CloudEvents(Vec<CloudEvent>),
+}
+
+impl WebhookPayload {
+    /// Flattens the payload into one event per changed entity.
+    #[must_use]
+    pub fn events(&self) -> Vec<WebhookEvent> {
+        match self {
+            WebhookPayload::Legacy(notifications) => notifications.events(),
+            WebhookPayload::CloudEvents(events) => {
+                events.iter().map(CloudEvent::to_event).collect()
+            }
+        }
+    }
+}
+
+/// Legacy webhook body.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookNotifications {
+    #[serde(default)]
+    pub event_notifications: Vec<EventNotification>,
+}
+
+impl WebhookNotifications {
+    /// Flattens the notifications into one event per changed entity.
+    #[must_use]
+    pub fn events(&self) -> Vec<WebhookEvent> {
+        self.event_notifications
+            .iter()
+            .flat_map(|n| {
+                n.data_change_event
+                    .entities
+                    .iter()
+                    .map(|e| e.to_event(&n.realm_id))
+            })
+            .collect()
+    }
+}
+
+/// Changes for a single company in a legacy webhook body.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EventNotification {
+    /// Company ID the changes belong to
+    pub realm_id: String,
+    pub data_change_event: DataChangeEvent,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct DataChangeEvent {
+    pub entities: Vec<EntityChange>,
+}
+
+/// A single changed entity in a legacy webhook body.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EntityChange {
+    /// Entity type name, e.g. `"Invoice"`
+    pub name: String,
+    /// ID of the changed entity
+    pub id: String,
+    pub operation: WebhookOperation,
+    /// Change time, e.g. `"2015-10-05T14:42:19-0700"`
+    pub last_updated: Option<String>,
+    /// ID of the entity merged into `id`, for `Merge` operations
+    pub deleted_id: Option<String>,
+}
+
+impl EntityChange {
+    /// The entity type, if it is one this crate models.
+    #[must_use]
+    pub fn kind(&self) -> Option<QBEntityKind> {
+        QBEntityKind::from_name(&self.name)
+    }
+
+    fn to_event(&self, realm_id: &str) -> WebhookEvent {
+        WebhookEvent {
+            realm_id: realm_id.into(),
+            entity_name: self.name.clone(),
+            kind: self.kind(),
+            id: self.id.clone(),
+            operation: self.operation,
+            last_updated: self.last_updated.as_deref().and_then(parse_timestamp),
+            deleted_id: self.deleted_id.clone(),
+        }
+    }
+}
+
+/// A single event in a `CloudEvents` webhook body.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct CloudEvent {
+    #[serde(rename = "specversion")]
+    pub spec_version: Option<String>,
+    /// Unique event ID
+    pub id: String,
+    pub source: Option<String>,
+    /// Event type, e.g. `"qbo.customer.created.v1"`
+    #[serde(rename = "type")]
+    pub event_type: String,
+    #[serde(rename = "datacontenttype")]
+    pub data_content_type: Option<String>,
+    /// Change time
+    pub time: Option<String>,
+    /// ID of the changed entity
+    #[serde(rename = "intuitentityid")]
+    pub intuit_entity_id: String,
+    /// Company ID the change belongs to
+    #[serde(rename = "intuitaccountid")]
+    pub intuit_account_id: String,
+    pub data: Option<serde_json::Value>,
+}
+
+impl CloudEvent {
+    /// Entity segment of the event type, e.g. `"customer"`.
+    #[must_use]
+    pub fn entity_name(&self) -> &str {
+        self.event_type.split('.').nth(1).unwrap_or_default()
+    }
+
+    /// The entity type, if it is one this crate models.
+    #[must_use]
+    pub fn kind(&self) -> Option<QBEntityKind> {
+        QBEntityKind::from_name(self.entity_name())
+    }
+
+    /// Operation segment of the event type, e.g. `created` → [`WebhookOperation::Create`].
+    #[must_use]
+    pub fn operation(&self) -> WebhookOperation {
+        match self.event_type.split('.').nth(2).unwrap_or_default() {
+            "created" => WebhookOperation::Create,
+            "updated" => WebhookOperation::Update,
+            "deleted" => WebhookOperation::Delete,
+            "merged" => WebhookOperation::Merge,
+            "voided" => WebhookOperation::Void,
+            "emailed" => WebhookOperation::Emailed,
+            _ => WebhookOperation::Other,
+        }
+    }
+
+    fn to_event(&self) -> WebhookEvent {
+        let kind = self.kind();
+        WebhookEvent {
+            realm_id: self.intuit_account_id.clone(),
+            entity_name: kind.map_or_else(|| self.entity_name().into(), |k| k.name().into()),
+            kind,
+            id: self.intuit_entity_id.clone(),
+            operation: self.operation(),
+            last_updated: self.time.as_deref().and_then(parse_timestamp),
+            deleted_id: None,
+        }
+    }
+}
+
+/// `WebhookOperation` Enum
+///
+/// Change reported for an entity.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub enum WebhookOperation {
+    Create,
+    Update,
+    Delete,
+    Merge,
+    Void,
+    Emailed,
+    #[default]
+    #[serde(other)]
+    Other,
+}
+
+/// A changed entity, normalized across both payload formats.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WebhookEvent {
+    /// Company ID the change belongs to
+    pub realm_id: String,
+    /// Entity type name as sent, normalized to [`QBItem::name()`](crate::QBItem::name) when known
+    pub entity_name: String,
+    /// The entity type, if it is one this crate models
+    pub kind: Option<QBEntityKind>,
+    /// ID of the changed entity
+    pub id: String,
+    pub operation: WebhookOperation,
+    pub last_updated: Option<DateTime<FixedOffset>>,
+    /// ID of the entity merged into `id`, for `Merge` operations
+    pub deleted_id: Option<String>,
+}
+
+// Legacy payloads use offsets without a colon (`-0700`), which RFC 3339 rejects.
+fn parse_timestamp(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_round_trip() {
+        let body = r#"{"eventNotifications":[{"realmId":"1","dataChangeEvent":{"entities":[]}}]}"#;
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"token").unwrap();
+        mac.update(body.as_bytes());
+        let signature = STANDARD.encode(mac.finalize().into_bytes());
+
+        assert!(verify_signature(body, &signature, "token"));
+        assert!(!verify_signature(body, &signature, "other"));
+        assert!(!verify_signature(format!("{body} "), &signature, "token"));
+        assert!(!verify_signature(body, "not base64!", "token"));
+    }
+
+    #[test]
+    fn parse_both_formats() {
+        let legacy = r#"{
+          "eventNotifications": [{
+            "realmId": "1185883450",
+            "dataChangeEvent": {
+              "entities": [
+                { "name": "Invoice", "id": "130", "operation": "Void", "lastUpdated": "2015-10-05T14:42:19-0700" },
+                { "name": "Customer", "id": "1", "operation": "Merge", "lastUpdated": "2015-10-05T14:42:19.000Z", "deletedId": "2" },
+                { "name": "JournalCode", "id": "7", "operation": "Create", "lastUpdated": "2015-10-05T14:42:19-0700" }
+              ]
+            }
+          }]
+        }"#;
+        let events = serde_json::from_str::<WebhookPayload>(legacy)
+            .unwrap()
+            .events();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].kind, Some(QBEntityKind::Invoice));
+        assert_eq!(events[0].operation, WebhookOperation::Void);
+        assert!(events[0].last_updated.is_some());
+        assert_eq!(events[1].deleted_id.as_deref(), Some("2"));
+        assert!(events[1].last_updated.is_some());
+        assert_eq!(events[2].kind, None);
+
+        let cloud = r#"[{
+          "specversion": "1.0",
+          "id": "88cd52a8-f918-4e6d-a7a4-4b9ee4a3bb1b",
+          "source": "intuit.dsnBgbseACLLRZNxo2dfc4evmEJdxde58xeeYcZliOU=",
+          "type": "qbo.salesreceipt.created.v1",
+          "datacontenttype": "application/json",
+          "time": "2025-09-10T21:31:25.179Z",
+          "intuitentityid": "1234",
+          "intuitaccountid": "310687",
+          "data": {}
+        }]"#;
+        let events = serde_json::from_str::<WebhookPayload>(cloud)
+            .unwrap()
+            .events();
+        assert_eq!(events[0].realm_id, "310687");
+        assert_eq!(events[0].entity_name, "SalesReceipt");
+        assert_eq!(events[0].kind, Some(QBEntityKind::SalesReceipt));
+        assert_eq!(events[0].operation, WebhookOperation::Create);
+    }
+}